@@ -1,17 +1,52 @@
-use crate::{sys::*, error::SdlError, utils::get_sys_error, subsystems::{SdlSubsystem, markers::{SdlSubsystemMarker, Timer, Audio}, SdlSubsystemFlag, AUDIO_INITIALIZED}};
-use std::{sync::atomic::{AtomicBool, Ordering}, marker::PhantomData, ffi::CString};
+use crate::{sys::*, error::SdlError, utils::get_sys_error, subsystems::{SdlSubsystem, markers::{SdlSubsystemMarker, Timer, Audio, Video, Joystick, Haptic, GameController, Events, Sensor}, SdlSubsystemFlag, SdlSubsystems, AUDIO_DRIVER_INITIALIZED}};
+use std::{sync::{Mutex, atomic::{AtomicBool, Ordering}}, rc::Rc, ffi::CString, thread::{self, ThreadId}};
 
 pub(crate) static INITIALIZED: AtomicBool = AtomicBool::new(false);
 
+/// The thread SDL2 was initialized on, while it is initialized.
+static INIT_THREAD: Mutex<Option<ThreadId>> = Mutex::new(Option::None);
+
 /// Represents an SDL2 context.
-pub struct SdlContext(
-    // PhantomData required to make this struct only initializable via `SdlContext::new`
-    PhantomData<u8>
-);
+/// 
+/// SDL2 stays initialized until both the context and every subsystem handle obtained
+/// from it are dropped, so dropping the context never invalidates live handles:
+/// 
+/// ```no_run
+/// # use sdl2_rs::SdlContext;
+/// let context = SdlContext::new()?;
+/// let timer = context.timer()?;
+/// drop(context);
+/// 
+/// // SDL2 is quit once the timer is dropped.
+/// timer.delay(10);
+/// # Ok::<(), sdl2_rs::SdlError>(())
+/// ```
+/// 
+/// Most of SDL2 may only be used on the thread it was initialized on, so neither
+/// the context nor the subsystem handles obtained from it are `Send` or `Sync`.
+/// Handles such as [`EventSender`](crate::subsystems::events::sender::EventSender)
+/// and [`Clock`](crate::subsystems::timer::Clock) wrap the functions which SDL2
+/// allows to be called from any thread.
+/// 
+/// ```compile_fail,E0277
+/// # use sdl2_rs::SdlContext;
+/// let context = SdlContext::new()?;
+/// std::thread::spawn(move || drop(context));
+/// # Ok::<(), sdl2_rs::SdlError>(())
+/// ```
+pub struct SdlContext {
+    // Required to make this struct only initializable via `SdlContext::new`,
+    // and to keep it on the thread it was created on.
+    pub(crate) state: Rc<ContextState>
+}
+
+/// State shared by the context and every subsystem handle, which quits SDL2 on drop.
+pub(crate) struct ContextState(());
 
-impl Drop for SdlContext {
+impl Drop for ContextState {
     fn drop(&mut self) {
         INITIALIZED.store(false, Ordering::SeqCst);        
+        *INIT_THREAD.lock().unwrap() = Option::None;
 
         #[cfg(feature = "log")]
         debug!("Calling 'SDL_Quit' via SdlContext drop.");
@@ -25,7 +60,7 @@ impl SdlContext {
     /// Initializes SDL and returns a context to it.
     /// 
     /// ### Errors
-    /// - [`SdlError::AlreadyInitialized`]
+    /// - [`SdlError::AlreadyInitialized`] if a context or any of its subsystem handles is still alive.
     /// - [`SdlError::SysError`]
     #[doc(alias = "SDL_Init")]
     pub fn new() -> Result<Self, SdlError> {
@@ -36,29 +71,77 @@ impl SdlContext {
         #[cfg(feature = "log")] debug!("Calling 'SDL_Init'");
         if unsafe { SDL_Init(0) == 0 } {
             INITIALIZED.store(true, Ordering::SeqCst);
+            *INIT_THREAD.lock().unwrap() = Option::Some(thread::current().id());
 
-            Ok(Self(PhantomData::default()))
+            Ok(Self { state: Rc::new(ContextState(())) })
         } else {
             Err(SdlError::SysError(get_sys_error().unwrap()))
         }
     }
 
-    fn init_subsystem<T: SdlSubsystemMarker>(&self, subsystem: SdlSubsystemFlag) -> Result<SdlSubsystem<T>, SdlError> {
-        let initialized = subsystem.initialized_raw();
+    /// Get the thread SDL2 was initialized on, if it is initialized.
+    pub fn init_thread() -> Option<ThreadId> {
+        *INIT_THREAD.lock().unwrap()
+    }
+
+    /// Returns true if SDL2 is initialized on the current thread.
+    pub fn is_init_thread() -> bool {
+        SdlContext::init_thread() == Option::Some(thread::current().id())
+    }
+
+    /// Initializes several subsystems at once, returning a handle for each of them.
+    /// 
+    /// Each handle keeps its subsystem and the subsystem's
+    /// [dependencies](SdlSubsystemFlag::dependencies) initialized, so dropping one handle
+    /// does not quit a subsystem another handle still depends on. If a subsystem fails
+    /// to initialize, the ones initialized before it are quit again.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::AlreadyInitialized`] if audio is initialized via [`SdlContext::audio_with_driver`].
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_InitSubSystem")]
+    pub fn init(&self, subsystems: &[SdlSubsystemFlag]) -> Result<SdlSubsystems, SdlError> {
+        let mut handles = SdlSubsystems::default();
 
-        if initialized.load(Ordering::SeqCst) {
-            return Err(SdlError::AlreadyInitialized(format!("The {:?} subsystem is already initialized.", subsystem)));
+        for (index, &subsystem) in subsystems.iter().enumerate() {
+            if subsystems[..index].contains(&subsystem) {
+                continue;
+            }
+
+            // Subsystems are initialized one by one, so each handle holds exactly
+            // one of SDL's references to its subsystem and dependencies.
+            match subsystem {
+                SdlSubsystemFlag::Timer => handles.timer = Some(self.timer()?),
+                SdlSubsystemFlag::Audio => handles.audio = Some(self.audio()?),
+                SdlSubsystemFlag::Video => handles.video = Some(self.video()?),
+                SdlSubsystemFlag::Joystick => handles.joystick = Some(self.joystick()?),
+                SdlSubsystemFlag::Haptic => handles.haptic = Some(self.haptic()?),
+                SdlSubsystemFlag::GameController => handles.game_controller = Some(self.game_controller()?),
+                SdlSubsystemFlag::Events => handles.events = Some(self.events()?),
+                SdlSubsystemFlag::Sensor => handles.sensor = Some(self.sensor()?)
+            }
         }
 
+        Ok(handles)
+    }
+
+    /// Get the subsystems which are currently initialized, either through handles
+    /// of their own or as dependencies of other subsystems.
+    #[doc(alias = "SDL_WasInit")]
+    pub fn initialized_subsystems(&self) -> Vec<SdlSubsystemFlag> {
+        SdlSubsystemFlag::ALL.into_iter().filter(SdlSubsystemFlag::initialized).collect()
+    }
+
+    fn init_subsystem<T: SdlSubsystemMarker>(&self, subsystem: SdlSubsystemFlag) -> Result<SdlSubsystem<T>, SdlError> {
+        // Initializing audio again would replace the driver chosen via `audio_with_driver`.
+        if subsystem == SdlSubsystemFlag::Audio && AUDIO_DRIVER_INITIALIZED.load(Ordering::SeqCst) {
+            return Err(SdlError::AlreadyInitialized(String::from("The Audio subsystem is already initialized with a specific driver.")));
+        }
+
+        // SDL only counts the reference if the subsystem is already initialized.
         #[cfg(feature = "log")] debug!("Calling 'SDL_InitSubSystem({:?})'", subsystem);
         if unsafe { SDL_InitSubSystem(subsystem as _) == 0 } {
-            initialized.store(true, Ordering::SeqCst);
-
-            Ok(SdlSubsystem {
-                t: Default::default(),
-                subsystem,
-                quitter: Option::None
-            })
+            Ok(SdlSubsystem::new(&self.state, subsystem, Option::None))
         } else {
             Err(SdlError::SysError(get_sys_error().unwrap()))
         }
@@ -67,7 +150,6 @@ impl SdlContext {
     /// Initializes the timer subsystem.
     /// 
     /// ### Errors
-    /// - [`SdlError::AlreadyInitialized`]
     /// - [`SdlError::SysError`]
     #[doc(alias = "SDL_InitSubSystem(SDL_INIT_TIMER)")]
     #[inline]
@@ -77,8 +159,11 @@ impl SdlContext {
 
     /// Initializes the audio subsystem.
     /// 
+    /// SDL also initializes the events subsystem, which audio depends on. Some SDL2 versions
+    /// do not quit the events subsystem along with audio, so it may stay initialized until SDL is quit.
+    /// 
     /// ### Errors
-    /// - [`SdlError::AlreadyInitialized`]
+    /// - [`SdlError::AlreadyInitialized`] if audio is initialized via [`SdlContext::audio_with_driver`].
     /// - [`SdlError::SysError`]
     #[doc(alias = "SDL_InitSubSystem(SDL_INIT_AUDIO)")]
     #[inline]
@@ -92,27 +177,109 @@ impl SdlContext {
     /// to designate a specific audio driver to be used.
     /// 
     /// ### Errors
-    /// - [`SdlError::AlreadyInitialized`]
+    /// - [`SdlError::AlreadyInitialized`] if audio is already initialized.
     /// - [`SdlError::SysError`]
     #[doc(alias = "SDL_AudioInit")]
     pub fn audio_with_driver(&self, driver_name: &str) -> Result<SdlSubsystem<Audio>, SdlError> {
-        if AUDIO_INITIALIZED.load(Ordering::SeqCst) {
-            return Err(SdlError::AlreadyInitialized(format!("The Audio subsystem is already initialized.")));
+        if SdlSubsystemFlag::Audio.initialized() {
+            return Err(SdlError::AlreadyInitialized(String::from("The Audio subsystem is already initialized.")));
         }
 
         let driver_name = CString::new(driver_name)?;
 
+        // `SDL_AudioInit` bypasses SDL's reference counts, so the events subsystem
+        // audio depends on is initialized separately.
+        #[cfg(feature = "log")] debug!("Calling 'SDL_InitSubSystem(Events)'");
+        if unsafe { SDL_InitSubSystem(SDL_INIT_EVENTS) } != 0 {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
         #[cfg(feature = "log")] debug!("Calling 'SDL_AudioInit'");
         if unsafe { SDL_AudioInit(driver_name.as_ptr()) == 0 } {
-            AUDIO_INITIALIZED.store(true, Ordering::SeqCst);
+            AUDIO_DRIVER_INITIALIZED.store(true, Ordering::SeqCst);
 
-            Ok(SdlSubsystem {
-                t: Default::default(),
-                subsystem: SdlSubsystemFlag::Audio,
-                quitter: Some(SDL_AudioQuit)
-            })
+            Ok(SdlSubsystem::new(&self.state, SdlSubsystemFlag::Audio, Some(quit_audio_with_driver)))
         } else {
-            Err(SdlError::SysError(get_sys_error().unwrap()))
+            let error = get_sys_error().unwrap();
+
+            #[cfg(feature = "log")] debug!("Calling 'SDL_QuitSubSystem(Events)'");
+            unsafe { SDL_QuitSubSystem(SDL_INIT_EVENTS) };
+
+            Err(SdlError::SysError(error))
         }
     }
+
+    /// Initializes the video subsystem.
+    /// 
+    /// SDL also initializes the events subsystem, which video depends on.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_InitSubSystem(SDL_INIT_VIDEO)")]
+    #[inline]
+    pub fn video(&self) -> Result<SdlSubsystem<Video>, SdlError> {
+        self.init_subsystem(SdlSubsystemFlag::Video)
+    }
+
+    /// Initializes the joystick subsystem.
+    /// 
+    /// SDL also initializes the events subsystem, which joysticks depend on.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_InitSubSystem(SDL_INIT_JOYSTICK)")]
+    #[inline]
+    pub fn joystick(&self) -> Result<SdlSubsystem<Joystick>, SdlError> {
+        self.init_subsystem(SdlSubsystemFlag::Joystick)
+    }
+
+    /// Initializes the haptic subsystem.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_InitSubSystem(SDL_INIT_HAPTIC)")]
+    #[inline]
+    pub fn haptic(&self) -> Result<SdlSubsystem<Haptic>, SdlError> {
+        self.init_subsystem(SdlSubsystemFlag::Haptic)
+    }
+
+    /// Initializes the game controller subsystem.
+    /// 
+    /// SDL also initializes the joystick subsystem, which game controllers depend on.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_InitSubSystem(SDL_INIT_GAMECONTROLLER)")]
+    #[inline]
+    pub fn game_controller(&self) -> Result<SdlSubsystem<GameController>, SdlError> {
+        self.init_subsystem(SdlSubsystemFlag::GameController)
+    }
+
+    /// Initializes the events subsystem.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_InitSubSystem(SDL_INIT_EVENTS)")]
+    #[inline]
+    pub fn events(&self) -> Result<SdlSubsystem<Events>, SdlError> {
+        self.init_subsystem(SdlSubsystemFlag::Events)
+    }
+
+    /// Initializes the sensor subsystem.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_InitSubSystem(SDL_INIT_SENSOR)")]
+    #[inline]
+    pub fn sensor(&self) -> Result<SdlSubsystem<Sensor>, SdlError> {
+        self.init_subsystem(SdlSubsystemFlag::Sensor)
+    }
+}
+
+/// Quits audio initialized via [`SdlContext::audio_with_driver`], along with its events dependency.
+unsafe extern "C" fn quit_audio_with_driver() {
+    AUDIO_DRIVER_INITIALIZED.store(false, Ordering::SeqCst);
+
+    SDL_AudioQuit();
+    SDL_QuitSubSystem(SDL_INIT_EVENTS);
 }
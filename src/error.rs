@@ -8,10 +8,31 @@ pub enum SdlError {
     #[error("{0}")]
     AlreadyInitialized(String),
 
+    /// SDL2 (or a subsystem) is not initialized.
+    #[error("{0}")]
+    NotInitialized(String),
+
+    /// A function was called on a thread other than the one SDL2 was initialized on.
+    #[error("{0}")]
+    WrongThread(String),
+
+    /// A value passed to SDL2 is invalid.
+    #[error("{0}")]
+    InvalidValue(String),
+
+    /// A function is not available in the linked SDL2 library,
+    /// or a feature is not supported by a device.
+    #[error("{0}")]
+    Unsupported(String),
+
     /// An error occured within SDL2 itself.
     #[error("{0}")]
     SysError(String),
 
+    /// An I/O error occured while reading or writing data.
+    #[error("{0}")]
+    IoError(String),
+
     /// See [`NulError`].
     #[error("{0}")]
     NulError(#[from] NulError)
@@ -0,0 +1,121 @@
+use std::{any::{Any, TypeId}, ffi::c_void, fmt, sync::Mutex};
+use crate::{sys::*, SdlError};
+
+/// Event types registered for custom events, keyed by the [`TypeId`] of their payload.
+static CUSTOM_EVENT_TYPES: Mutex<Vec<(TypeId, u32)>> = Mutex::new(Vec::new());
+
+/// Reserve `count` consecutive user event types, returning the first.
+pub(crate) fn register_events(count: u32) -> Result<u32, SdlError> {
+    #[cfg(feature = "log")] debug!("Calling 'SDL_RegisterEvents'");
+    let result = unsafe { SDL_RegisterEvents(count as _) };
+
+    // SDL does not set an error message when it runs out of event types.
+    if result != u32::MAX {
+        Ok(result)
+    } else {
+        Err(SdlError::SysError(format!("Unable to register {} user event type(s).", count)))
+    }
+}
+
+/// Get the event type used for custom events carrying a `T`, registering one if needed.
+pub(crate) fn register_custom_event<T: 'static + Send>() -> Result<u32, SdlError> {
+    let mut types = CUSTOM_EVENT_TYPES.lock().unwrap();
+    let type_id = TypeId::of::<T>();
+
+    if let Some((_, event_type)) = types.iter().find(|(id, _)| *id == type_id) {
+        return Ok(*event_type);
+    }
+
+    let event_type = register_events(1)?;
+    types.push((type_id, event_type));
+
+    Ok(event_type)
+}
+
+/// Returns true if `event_type` was registered for custom events.
+pub(crate) fn is_custom_event_type(event_type: u32) -> bool {
+    CUSTOM_EVENT_TYPES.lock().unwrap().iter().any(|(_, t)| *t == event_type)
+}
+
+/// The Rust value carried by a custom event, see [`SdlSubsystem<Events>::push_custom_event`](crate::subsystems::SdlSubsystem::push_custom_event).
+/// 
+/// The value is dropped along with the event that owns it.
+pub struct CustomEventData(pub(crate) CustomPayload);
+
+pub(crate) enum CustomPayload {
+    /// The payload was taken from an event removed from the queue.
+    Owned(Box<dyn Any + Send>),
+
+    /// The payload belongs to an event still owned by SDL, such as
+    /// one passed to an event filter or watcher.
+    Borrowed(*const (dyn Any + Send)),
+
+    /// The payload belongs to an event that was peeked at, and may be freed
+    /// at any time, so it cannot be accessed.
+    Detached
+}
+
+impl CustomEventData {
+    /// Wrap an owned value.
+    pub(crate) fn new<T: 'static + Send>(value: T) -> CustomEventData {
+        CustomEventData(CustomPayload::Owned(Box::new(value)))
+    }
+
+    /// Move the payload into an SDL event's `data1` field.
+    /// 
+    /// Borrowed payloads cannot be moved, and are stored as a null pointer.
+    pub(crate) fn into_ll(self) -> *mut c_void {
+        match self.0 {
+            CustomPayload::Owned(value) => Box::into_raw(Box::new(value)) as _,
+            CustomPayload::Borrowed(_) | CustomPayload::Detached => std::ptr::null_mut()
+        }
+    }
+
+    /// Take back ownership of a payload stored via [`CustomEventData::into_ll`].
+    /// 
+    /// ### Safety
+    /// `data1` must come from [`CustomEventData::into_ll`], and must not be used again.
+    pub(crate) unsafe fn from_ll(data1: *mut c_void) -> CustomEventData {
+        CustomEventData(CustomPayload::Owned(*Box::from_raw(data1 as *mut Box<dyn Any + Send>)))
+    }
+
+    /// Borrow a payload stored via [`CustomEventData::into_ll`].
+    /// 
+    /// ### Safety
+    /// `data1` must come from [`CustomEventData::into_ll`], and must outlive the returned value.
+    pub(crate) unsafe fn borrow_ll(data1: *mut c_void) -> CustomEventData {
+        CustomEventData(CustomPayload::Borrowed((*(data1 as *const Box<dyn Any + Send>)).as_ref()))
+    }
+
+    fn as_any(&self) -> Option<&(dyn Any + Send)> {
+        match &self.0 {
+            CustomPayload::Owned(value) => Option::Some(value.as_ref()),
+            CustomPayload::Borrowed(value) => Option::Some(unsafe { &**value }),
+            CustomPayload::Detached => Option::None
+        }
+    }
+
+    /// Get a reference to the payload if it is a `T`.
+    /// 
+    /// Always returns [`Option::None`] for events that were only peeked at,
+    /// see [`SdlSubsystem<Events>::peep_events`](crate::subsystems::SdlSubsystem::peep_events).
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.as_any()?.downcast_ref()
+    }
+}
+
+impl fmt::Debug for CustomEventData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomEventData").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for CustomEventData {
+    /// Payloads are only equal if they are the same allocation.
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_any(), other.as_any()) {
+            (Option::Some(a), Option::Some(b)) => std::ptr::eq(a as *const _ as *const u8, b as *const _ as *const u8),
+            _ => false
+        }
+    }
+}
@@ -0,0 +1,1334 @@
+use std::{ffi::{CStr, CString}, mem, ptr};
+use crate::{sys::*, SdlError, subsystems::{video::mouse::MouseButtons, joystick::HatState}};
+use super::{custom::{CustomEventData, CustomPayload, is_custom_event_type}, keycode::Keycode, scancode::Scancode, keyboard::Mod};
+
+/// The types of events that can be delivered by SDL.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventType {
+    First = SDL_EventType::SDL_FIRSTEVENT as _,
+
+    Quit = SDL_EventType::SDL_QUIT as _,
+    AppTerminating = SDL_EventType::SDL_APP_TERMINATING as _,
+    AppLowMemory = SDL_EventType::SDL_APP_LOWMEMORY as _,
+    AppWillEnterBackground = SDL_EventType::SDL_APP_WILLENTERBACKGROUND as _,
+    AppDidEnterBackground = SDL_EventType::SDL_APP_DIDENTERBACKGROUND as _,
+    AppWillEnterForeground = SDL_EventType::SDL_APP_WILLENTERFOREGROUND as _,
+    AppDidEnterForeground = SDL_EventType::SDL_APP_DIDENTERFOREGROUND as _,
+    LocaleChanged = SDL_EventType::SDL_LOCALECHANGED as _,
+
+    Display = SDL_EventType::SDL_DISPLAYEVENT as _,
+
+    Window = SDL_EventType::SDL_WINDOWEVENT as _,
+    SysWm = SDL_EventType::SDL_SYSWMEVENT as _,
+
+    KeyDown = SDL_EventType::SDL_KEYDOWN as _,
+    KeyUp = SDL_EventType::SDL_KEYUP as _,
+    TextEditing = SDL_EventType::SDL_TEXTEDITING as _,
+    TextInput = SDL_EventType::SDL_TEXTINPUT as _,
+    KeymapChanged = SDL_EventType::SDL_KEYMAPCHANGED as _,
+    /// Composition text too long for [`EventType::TextEditing`], sent by SDL 2.0.22
+    /// and later when the `SDL_HINT_IME_SUPPORT_EXTENDED_TEXT` hint is set.
+    /// 
+    /// Decoded as [`Event::TextEditing`].
+    TextEditingExt = SDL_TEXTEDITING_EXT,
+
+    MouseMotion = SDL_EventType::SDL_MOUSEMOTION as _,
+    MouseButtonDown = SDL_EventType::SDL_MOUSEBUTTONDOWN as _,
+    MouseButtonUp = SDL_EventType::SDL_MOUSEBUTTONUP as _,
+    MouseWheel = SDL_EventType::SDL_MOUSEWHEEL as _,
+
+    JoyAxisMotion = SDL_EventType::SDL_JOYAXISMOTION as _,
+    JoyBallMotion = SDL_EventType::SDL_JOYBALLMOTION as _,
+    JoyHatMotion = SDL_EventType::SDL_JOYHATMOTION as _,
+    JoyButtonDown = SDL_EventType::SDL_JOYBUTTONDOWN as _,
+    JoyButtonUp = SDL_EventType::SDL_JOYBUTTONUP as _,
+    JoyDeviceAdded = SDL_EventType::SDL_JOYDEVICEADDED as _,
+    JoyDeviceRemoved = SDL_EventType::SDL_JOYDEVICEREMOVED as _,
+
+    ControllerAxisMotion = SDL_EventType::SDL_CONTROLLERAXISMOTION as _,
+    ControllerButtonDown = SDL_EventType::SDL_CONTROLLERBUTTONDOWN as _,
+    ControllerButtonUp = SDL_EventType::SDL_CONTROLLERBUTTONUP as _,
+    ControllerDeviceAdded = SDL_EventType::SDL_CONTROLLERDEVICEADDED as _,
+    ControllerDeviceRemoved = SDL_EventType::SDL_CONTROLLERDEVICEREMOVED as _,
+    ControllerDeviceRemapped = SDL_EventType::SDL_CONTROLLERDEVICEREMAPPED as _,
+    ControllerTouchpadDown = SDL_EventType::SDL_CONTROLLERTOUCHPADDOWN as _,
+    ControllerTouchpadMotion = SDL_EventType::SDL_CONTROLLERTOUCHPADMOTION as _,
+    ControllerTouchpadUp = SDL_EventType::SDL_CONTROLLERTOUCHPADUP as _,
+    ControllerSensorUpdate = SDL_EventType::SDL_CONTROLLERSENSORUPDATE as _,
+
+    FingerDown = SDL_EventType::SDL_FINGERDOWN as _,
+    FingerUp = SDL_EventType::SDL_FINGERUP as _,
+    FingerMotion = SDL_EventType::SDL_FINGERMOTION as _,
+
+    DollarGesture = SDL_EventType::SDL_DOLLARGESTURE as _,
+    DollarRecord = SDL_EventType::SDL_DOLLARRECORD as _,
+    MultiGesture = SDL_EventType::SDL_MULTIGESTURE as _,
+
+    ClipboardUpdate = SDL_EventType::SDL_CLIPBOARDUPDATE as _,
+
+    DropFile = SDL_EventType::SDL_DROPFILE as _,
+    DropText = SDL_EventType::SDL_DROPTEXT as _,
+    DropBegin = SDL_EventType::SDL_DROPBEGIN as _,
+    DropComplete = SDL_EventType::SDL_DROPCOMPLETE as _,
+
+    AudioDeviceAdded = SDL_EventType::SDL_AUDIODEVICEADDED as _,
+    AudioDeviceRemoved = SDL_EventType::SDL_AUDIODEVICEREMOVED as _,
+
+    SensorUpdate = SDL_EventType::SDL_SENSORUPDATE as _,
+
+    RenderTargetsReset = SDL_EventType::SDL_RENDER_TARGETS_RESET as _,
+    RenderDeviceReset = SDL_EventType::SDL_RENDER_DEVICE_RESET as _,
+
+    /// The first event type available for user events.
+    /// 
+    /// Event types between this and [`EventType::Last`] are reserved for user events.
+    User = SDL_EventType::SDL_USEREVENT as _,
+
+    Last = SDL_EventType::SDL_LASTEVENT as _
+}
+
+impl EventType {
+    /// Get the [`EventType`] matching a raw SDL event type.
+    /// 
+    /// Returns [`Option::None`] for registered user event types, and for
+    /// event types unknown to this library.
+    pub(crate) fn from_ll(raw: u32) -> Option<EventType> {
+        use SDL_EventType::*;
+
+        Option::Some(match raw {
+            x if x == SDL_FIRSTEVENT as u32 => EventType::First,
+            x if x == SDL_QUIT as u32 => EventType::Quit,
+            x if x == SDL_APP_TERMINATING as u32 => EventType::AppTerminating,
+            x if x == SDL_APP_LOWMEMORY as u32 => EventType::AppLowMemory,
+            x if x == SDL_APP_WILLENTERBACKGROUND as u32 => EventType::AppWillEnterBackground,
+            x if x == SDL_APP_DIDENTERBACKGROUND as u32 => EventType::AppDidEnterBackground,
+            x if x == SDL_APP_WILLENTERFOREGROUND as u32 => EventType::AppWillEnterForeground,
+            x if x == SDL_APP_DIDENTERFOREGROUND as u32 => EventType::AppDidEnterForeground,
+            x if x == SDL_LOCALECHANGED as u32 => EventType::LocaleChanged,
+            x if x == SDL_DISPLAYEVENT as u32 => EventType::Display,
+            x if x == SDL_WINDOWEVENT as u32 => EventType::Window,
+            x if x == SDL_SYSWMEVENT as u32 => EventType::SysWm,
+            x if x == SDL_KEYDOWN as u32 => EventType::KeyDown,
+            x if x == SDL_KEYUP as u32 => EventType::KeyUp,
+            x if x == SDL_TEXTEDITING as u32 => EventType::TextEditing,
+            x if x == SDL_TEXTINPUT as u32 => EventType::TextInput,
+            x if x == SDL_KEYMAPCHANGED as u32 => EventType::KeymapChanged,
+            SDL_TEXTEDITING_EXT => EventType::TextEditingExt,
+            x if x == SDL_MOUSEMOTION as u32 => EventType::MouseMotion,
+            x if x == SDL_MOUSEBUTTONDOWN as u32 => EventType::MouseButtonDown,
+            x if x == SDL_MOUSEBUTTONUP as u32 => EventType::MouseButtonUp,
+            x if x == SDL_MOUSEWHEEL as u32 => EventType::MouseWheel,
+            x if x == SDL_JOYAXISMOTION as u32 => EventType::JoyAxisMotion,
+            x if x == SDL_JOYBALLMOTION as u32 => EventType::JoyBallMotion,
+            x if x == SDL_JOYHATMOTION as u32 => EventType::JoyHatMotion,
+            x if x == SDL_JOYBUTTONDOWN as u32 => EventType::JoyButtonDown,
+            x if x == SDL_JOYBUTTONUP as u32 => EventType::JoyButtonUp,
+            x if x == SDL_JOYDEVICEADDED as u32 => EventType::JoyDeviceAdded,
+            x if x == SDL_JOYDEVICEREMOVED as u32 => EventType::JoyDeviceRemoved,
+            x if x == SDL_CONTROLLERAXISMOTION as u32 => EventType::ControllerAxisMotion,
+            x if x == SDL_CONTROLLERBUTTONDOWN as u32 => EventType::ControllerButtonDown,
+            x if x == SDL_CONTROLLERBUTTONUP as u32 => EventType::ControllerButtonUp,
+            x if x == SDL_CONTROLLERDEVICEADDED as u32 => EventType::ControllerDeviceAdded,
+            x if x == SDL_CONTROLLERDEVICEREMOVED as u32 => EventType::ControllerDeviceRemoved,
+            x if x == SDL_CONTROLLERDEVICEREMAPPED as u32 => EventType::ControllerDeviceRemapped,
+            x if x == SDL_CONTROLLERTOUCHPADDOWN as u32 => EventType::ControllerTouchpadDown,
+            x if x == SDL_CONTROLLERTOUCHPADMOTION as u32 => EventType::ControllerTouchpadMotion,
+            x if x == SDL_CONTROLLERTOUCHPADUP as u32 => EventType::ControllerTouchpadUp,
+            x if x == SDL_CONTROLLERSENSORUPDATE as u32 => EventType::ControllerSensorUpdate,
+            x if x == SDL_FINGERDOWN as u32 => EventType::FingerDown,
+            x if x == SDL_FINGERUP as u32 => EventType::FingerUp,
+            x if x == SDL_FINGERMOTION as u32 => EventType::FingerMotion,
+            x if x == SDL_DOLLARGESTURE as u32 => EventType::DollarGesture,
+            x if x == SDL_DOLLARRECORD as u32 => EventType::DollarRecord,
+            x if x == SDL_MULTIGESTURE as u32 => EventType::MultiGesture,
+            x if x == SDL_CLIPBOARDUPDATE as u32 => EventType::ClipboardUpdate,
+            x if x == SDL_DROPFILE as u32 => EventType::DropFile,
+            x if x == SDL_DROPTEXT as u32 => EventType::DropText,
+            x if x == SDL_DROPBEGIN as u32 => EventType::DropBegin,
+            x if x == SDL_DROPCOMPLETE as u32 => EventType::DropComplete,
+            x if x == SDL_AUDIODEVICEADDED as u32 => EventType::AudioDeviceAdded,
+            x if x == SDL_AUDIODEVICEREMOVED as u32 => EventType::AudioDeviceRemoved,
+            x if x == SDL_SENSORUPDATE as u32 => EventType::SensorUpdate,
+            x if x == SDL_RENDER_TARGETS_RESET as u32 => EventType::RenderTargetsReset,
+            x if x == SDL_RENDER_DEVICE_RESET as u32 => EventType::RenderDeviceReset,
+            x if x == SDL_USEREVENT as u32 => EventType::User,
+            x if x == SDL_LASTEVENT as u32 => EventType::Last,
+            _ => return Option::None
+        })
+    }
+}
+
+/// Events relating to a single window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowEvent {
+    None,
+    Shown,
+    Hidden,
+    Exposed,
+    Moved(i32, i32),
+    Resized(i32, i32),
+    SizeChanged(i32, i32),
+    Minimized,
+    Maximized,
+    Restored,
+    Enter,
+    Leave,
+    FocusGained,
+    FocusLost,
+    Close,
+    TakeFocus,
+    HitTest
+}
+
+impl WindowEvent {
+    fn from_ll(id: u8, data1: i32, data2: i32) -> WindowEvent {
+        use SDL_WindowEventID::*;
+
+        match id as u32 {
+            x if x == SDL_WINDOWEVENT_SHOWN as u32 => WindowEvent::Shown,
+            x if x == SDL_WINDOWEVENT_HIDDEN as u32 => WindowEvent::Hidden,
+            x if x == SDL_WINDOWEVENT_EXPOSED as u32 => WindowEvent::Exposed,
+            x if x == SDL_WINDOWEVENT_MOVED as u32 => WindowEvent::Moved(data1, data2),
+            x if x == SDL_WINDOWEVENT_RESIZED as u32 => WindowEvent::Resized(data1, data2),
+            x if x == SDL_WINDOWEVENT_SIZE_CHANGED as u32 => WindowEvent::SizeChanged(data1, data2),
+            x if x == SDL_WINDOWEVENT_MINIMIZED as u32 => WindowEvent::Minimized,
+            x if x == SDL_WINDOWEVENT_MAXIMIZED as u32 => WindowEvent::Maximized,
+            x if x == SDL_WINDOWEVENT_RESTORED as u32 => WindowEvent::Restored,
+            x if x == SDL_WINDOWEVENT_ENTER as u32 => WindowEvent::Enter,
+            x if x == SDL_WINDOWEVENT_LEAVE as u32 => WindowEvent::Leave,
+            x if x == SDL_WINDOWEVENT_FOCUS_GAINED as u32 => WindowEvent::FocusGained,
+            x if x == SDL_WINDOWEVENT_FOCUS_LOST as u32 => WindowEvent::FocusLost,
+            x if x == SDL_WINDOWEVENT_CLOSE as u32 => WindowEvent::Close,
+            x if x == SDL_WINDOWEVENT_TAKE_FOCUS as u32 => WindowEvent::TakeFocus,
+            x if x == SDL_WINDOWEVENT_HIT_TEST as u32 => WindowEvent::HitTest,
+            _ => WindowEvent::None
+        }
+    }
+}
+
+/// Events relating to a single display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayEvent {
+    None,
+
+    /// The display orientation changed, containing the raw `SDL_DisplayOrientation`.
+    Orientation(i32),
+    Connected,
+    Disconnected
+}
+
+impl DisplayEvent {
+    fn from_ll(id: u8, data1: i32) -> DisplayEvent {
+        use SDL_DisplayEventID::*;
+
+        match id as u32 {
+            x if x == SDL_DISPLAYEVENT_ORIENTATION as u32 => DisplayEvent::Orientation(data1),
+            x if x == SDL_DISPLAYEVENT_CONNECTED as u32 => DisplayEvent::Connected,
+            x if x == SDL_DISPLAYEVENT_DISCONNECTED as u32 => DisplayEvent::Disconnected,
+            _ => DisplayEvent::None
+        }
+    }
+}
+
+/// A mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+    X1,
+    X2,
+
+    /// A button without a dedicated SDL constant.
+    Unknown(u8)
+}
+
+impl MouseButton {
+    pub(crate) fn from_ll(button: u8) -> MouseButton {
+        match button as u32 {
+            SDL_BUTTON_LEFT => MouseButton::Left,
+            SDL_BUTTON_MIDDLE => MouseButton::Middle,
+            SDL_BUTTON_RIGHT => MouseButton::Right,
+            SDL_BUTTON_X1 => MouseButton::X1,
+            SDL_BUTTON_X2 => MouseButton::X2,
+            _ => MouseButton::Unknown(button)
+        }
+    }
+
+    pub(crate) fn to_ll(self) -> u8 {
+        match self {
+            MouseButton::Left => SDL_BUTTON_LEFT as _,
+            MouseButton::Middle => SDL_BUTTON_MIDDLE as _,
+            MouseButton::Right => SDL_BUTTON_RIGHT as _,
+            MouseButton::X1 => SDL_BUTTON_X1 as _,
+            MouseButton::X2 => SDL_BUTTON_X2 as _,
+            MouseButton::Unknown(button) => button
+        }
+    }
+}
+
+/// The scroll direction reported by a mouse wheel event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseWheelDirection {
+    Normal,
+
+    /// The values in the event are negated, as the platform uses natural scrolling.
+    Flipped
+}
+
+/// A typed SDL event.
+/// 
+/// Every variant carries the `timestamp` at which SDL created the event,
+/// in milliseconds since the [`SdlContext`](crate::SdlContext) was initialized.
+#[derive(Debug, PartialEq)]
+pub enum Event {
+    Quit { timestamp: u32 },
+
+    AppTerminating { timestamp: u32 },
+    AppLowMemory { timestamp: u32 },
+    AppWillEnterBackground { timestamp: u32 },
+    AppDidEnterBackground { timestamp: u32 },
+    AppWillEnterForeground { timestamp: u32 },
+    AppDidEnterForeground { timestamp: u32 },
+    LocaleChanged { timestamp: u32 },
+
+    Display { timestamp: u32, display_index: u32, display_event: DisplayEvent },
+    Window { timestamp: u32, window_id: u32, win_event: WindowEvent },
+
+    KeyDown { timestamp: u32, window_id: u32, keycode: Keycode, scancode: Scancode, keymod: Mod, repeat: bool },
+    KeyUp { timestamp: u32, window_id: u32, keycode: Keycode, scancode: Scancode, keymod: Mod, repeat: bool },
+    /// Text being composed by an input method (IME).
+    /// 
+    /// `start` is the cursor position and `length` the length of the selection,
+    /// both in characters of `text`.
+    TextEditing { timestamp: u32, window_id: u32, text: String, start: i32, length: i32 },
+    /// Text committed by the keyboard or an input method (IME).
+    TextInput { timestamp: u32, window_id: u32, text: String },
+    KeymapChanged { timestamp: u32 },
+
+    MouseMotion { timestamp: u32, window_id: u32, which: u32, mousestate: MouseButtons, x: i32, y: i32, xrel: i32, yrel: i32 },
+    MouseButtonDown { timestamp: u32, window_id: u32, which: u32, mouse_btn: MouseButton, clicks: u8, x: i32, y: i32 },
+    MouseButtonUp { timestamp: u32, window_id: u32, which: u32, mouse_btn: MouseButton, clicks: u8, x: i32, y: i32 },
+    MouseWheel { timestamp: u32, window_id: u32, which: u32, x: i32, y: i32, direction: MouseWheelDirection },
+
+    JoyAxisMotion { timestamp: u32, which: i32, axis_idx: u8, value: i16 },
+    JoyBallMotion { timestamp: u32, which: i32, ball_idx: u8, xrel: i16, yrel: i16 },
+    JoyHatMotion { timestamp: u32, which: i32, hat_idx: u8, value: HatState },
+    JoyButtonDown { timestamp: u32, which: i32, button_idx: u8 },
+    JoyButtonUp { timestamp: u32, which: i32, button_idx: u8 },
+    JoyDeviceAdded { timestamp: u32, which: u32 },
+    JoyDeviceRemoved { timestamp: u32, which: i32 },
+
+    ControllerAxisMotion { timestamp: u32, which: i32, axis_idx: u8, value: i16 },
+    ControllerButtonDown { timestamp: u32, which: i32, button_idx: u8 },
+    ControllerButtonUp { timestamp: u32, which: i32, button_idx: u8 },
+    ControllerDeviceAdded { timestamp: u32, which: u32 },
+    ControllerDeviceRemoved { timestamp: u32, which: i32 },
+    ControllerDeviceRemapped { timestamp: u32, which: i32 },
+    ControllerTouchpadDown { timestamp: u32, which: i32, touchpad: i32, finger: i32, x: f32, y: f32, pressure: f32 },
+    ControllerTouchpadMotion { timestamp: u32, which: i32, touchpad: i32, finger: i32, x: f32, y: f32, pressure: f32 },
+    ControllerTouchpadUp { timestamp: u32, which: i32, touchpad: i32, finger: i32, x: f32, y: f32, pressure: f32 },
+    ControllerSensorUpdate { timestamp: u32, which: i32, sensor: i32, data: [f32; 3] },
+
+    /// A finger touched a touch device, where `x`, `y`, `dx` and `dy` are normalized between 0 and 1.
+    FingerDown { timestamp: u32, touch_id: i64, finger_id: i64, x: f32, y: f32, dx: f32, dy: f32, pressure: f32, window_id: u32 },
+    FingerUp { timestamp: u32, touch_id: i64, finger_id: i64, x: f32, y: f32, dx: f32, dy: f32, pressure: f32, window_id: u32 },
+    FingerMotion { timestamp: u32, touch_id: i64, finger_id: i64, x: f32, y: f32, dx: f32, dy: f32, pressure: f32, window_id: u32 },
+
+    /// A gesture matched a dollar template, where `error` is the difference to the template.
+    DollarGesture { timestamp: u32, touch_id: i64, gesture_id: i64, num_fingers: u32, error: f32, x: f32, y: f32 },
+    /// A gesture was recorded as a dollar template, see
+    /// [`SdlSubsystem<Video>::record_gesture`](crate::subsystems::SdlSubsystem::record_gesture).
+    DollarRecord { timestamp: u32, touch_id: i64, gesture_id: i64, num_fingers: u32, error: f32, x: f32, y: f32 },
+    /// Several fingers rotated by `d_theta` radians and pinched by `d_dist` around (`x`, `y`).
+    MultiGesture { timestamp: u32, touch_id: i64, d_theta: f32, d_dist: f32, x: f32, y: f32, num_fingers: u16 },
+
+    ClipboardUpdate { timestamp: u32 },
+
+    DropFile { timestamp: u32, window_id: u32, filename: String },
+    DropText { timestamp: u32, window_id: u32, text: String },
+    DropBegin { timestamp: u32, window_id: u32 },
+    DropComplete { timestamp: u32, window_id: u32 },
+
+    AudioDeviceAdded { timestamp: u32, which: u32, iscapture: bool },
+    AudioDeviceRemoved { timestamp: u32, which: u32, iscapture: bool },
+
+    SensorUpdate { timestamp: u32, which: i32, data: [f32; 6] },
+
+    RenderTargetsReset { timestamp: u32 },
+    RenderDeviceReset { timestamp: u32 },
+
+    /// An event of a type between [`EventType::User`] and [`EventType::Last`].
+    /// 
+    /// Types below [`EventType::User`] or registered for custom events cannot be pushed with this variant.
+    User { timestamp: u32, window_id: u32, type_: u32, code: i32, data1: *mut std::ffi::c_void, data2: *mut std::ffi::c_void },
+
+    /// A user event carrying a Rust value, see [`Event::as_user_event`].
+    Custom { timestamp: u32, type_: u32, data: CustomEventData },
+
+    /// An event this library does not decode.
+    Unknown { timestamp: u32, type_: u32 }
+}
+
+/// The event type of `SDL_TextEditingExtEvent`, which is newer than the bindings.
+const SDL_TEXTEDITING_EXT: u32 = 0x305;
+
+/// `SDL_TextEditingExtEvent`, which is newer than the bindings.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct SDL_TextEditingExtEvent {
+    type_: u32,
+    timestamp: u32,
+    window_id: u32,
+    text: *mut std::ffi::c_char,
+    start: i32,
+    length: i32
+}
+
+/// Copies a nul-terminated C string out of an SDL event.
+unsafe fn text_from_ll(text: *const std::ffi::c_char) -> String {
+    CStr::from_ptr(text).to_string_lossy().into_owned()
+}
+
+/// Returns true if events of type `type_` hold a pointer, which is read or freed
+/// when the event is removed from the queue.
+fn holds_pointer(type_: u32) -> bool {
+    type_ == EventType::DropFile as u32
+        || type_ == EventType::DropText as u32
+        || type_ == EventType::TextEditingExt as u32
+        || type_ == EventType::SysWm as u32
+}
+
+/// Copies `text` into the fixed size buffer of an SDL text event, truncating
+/// at a character boundary if it does not fit.
+fn text_to_ll(text: &str) -> [std::ffi::c_char; 32] {
+    let mut buffer = [0; 32];
+    let mut len = text.len().min(buffer.len() - 1);
+
+    while !text.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    for (dst, src) in buffer.iter_mut().zip(&text.as_bytes()[..len]) {
+        *dst = *src as _;
+    }
+
+    buffer
+}
+
+/// Copies `text` into a string allocated by SDL, as used by drop events.
+fn text_to_sdl_alloc(text: &str) -> *mut std::ffi::c_char {
+    // Interior nul bytes cannot be represented, so the text is cut at the first one.
+    let text = CString::new(text.split('\0').next().unwrap_or_default()).unwrap();
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_strdup'");
+    unsafe { SDL_strdup(text.as_ptr()) }
+}
+
+impl Event {
+    /// Convert a raw SDL event into an [`Event`], taking ownership of any
+    /// memory SDL allocated for it.
+    /// 
+    /// ### Safety
+    /// `raw` must be a valid event that was just removed from the event queue.
+    pub(crate) unsafe fn from_ll(raw: SDL_Event) -> Event {
+        Event::convert_ll(&raw, true)
+    }
+
+    /// Convert a raw SDL event into an [`Event`] without taking ownership of
+    /// any data it points to.
+    /// 
+    /// Custom event payloads are borrowed, so the returned event must not outlive `raw`.
+    /// 
+    /// ### Safety
+    /// `raw` must be a valid event that is still owned by SDL or the caller.
+    pub(crate) unsafe fn from_ll_borrowed(raw: &SDL_Event) -> Event {
+        Event::convert_ll(raw, false)
+    }
+
+    /// Convert a raw SDL event into an [`Event`] which does not point to any
+    /// data owned by `raw`, so that it may outlive it.
+    /// 
+    /// The payload of custom events is not accessible from the returned event.
+    /// 
+    /// ### Safety
+    /// `raw` must be a valid event that is still owned by SDL or the caller.
+    pub(crate) unsafe fn from_ll_detached(raw: &SDL_Event) -> Event {
+        match Event::convert_ll(raw, false) {
+            Event::Custom { timestamp, type_, .. } => Event::Custom {
+                timestamp,
+                type_,
+                data: CustomEventData(CustomPayload::Detached)
+            },
+            event => event
+        }
+    }
+
+    unsafe fn convert_ll(raw: &SDL_Event, owned: bool) -> Event {
+        let type_ = raw.type_;
+        let timestamp = raw.common.timestamp;
+
+        let event_type = match EventType::from_ll(type_) {
+            Option::Some(event_type) => event_type,
+            Option::None if type_ >= EventType::User as u32 => EventType::User,
+            Option::None => return Event::Unknown { timestamp, type_ }
+        };
+
+        match event_type {
+            EventType::Quit => Event::Quit { timestamp },
+            EventType::AppTerminating => Event::AppTerminating { timestamp },
+            EventType::AppLowMemory => Event::AppLowMemory { timestamp },
+            EventType::AppWillEnterBackground => Event::AppWillEnterBackground { timestamp },
+            EventType::AppDidEnterBackground => Event::AppDidEnterBackground { timestamp },
+            EventType::AppWillEnterForeground => Event::AppWillEnterForeground { timestamp },
+            EventType::AppDidEnterForeground => Event::AppDidEnterForeground { timestamp },
+            EventType::LocaleChanged => Event::LocaleChanged { timestamp },
+
+            EventType::Display => {
+                let event = raw.display;
+                Event::Display {
+                    timestamp,
+                    display_index: event.display,
+                    display_event: DisplayEvent::from_ll(event.event, event.data1)
+                }
+            }
+            EventType::Window => {
+                let event = raw.window;
+                Event::Window {
+                    timestamp,
+                    window_id: event.windowID,
+                    win_event: WindowEvent::from_ll(event.event, event.data1, event.data2)
+                }
+            }
+
+            EventType::KeyDown | EventType::KeyUp => {
+                let event = raw.key;
+
+                // `SDL_Scancode` is a Rust enum, so the scancode is read as its raw value
+                // in case SDL reports one unknown to the bindings.
+                let scancode = ptr::addr_of!(raw.key.keysym.scancode).cast::<u32>().read();
+
+                let (window_id, keycode, scancode, keymod, repeat) = (
+                    event.windowID,
+                    Keycode(event.keysym.sym),
+                    Scancode(scancode),
+                    Mod::from(event.keysym.mod_),
+                    event.repeat != 0
+                );
+
+                if event_type == EventType::KeyDown {
+                    Event::KeyDown { timestamp, window_id, keycode, scancode, keymod, repeat }
+                } else {
+                    Event::KeyUp { timestamp, window_id, keycode, scancode, keymod, repeat }
+                }
+            }
+            EventType::TextEditing => {
+                let event = raw.edit;
+                Event::TextEditing {
+                    timestamp,
+                    window_id: event.windowID,
+                    text: text_from_ll(event.text.as_ptr()),
+                    start: event.start,
+                    length: event.length
+                }
+            }
+            EventType::TextEditingExt => {
+                let event = ptr::from_ref(raw).cast::<SDL_TextEditingExtEvent>().read();
+
+                // Like drop events, the text is allocated by SDL and must be freed by
+                // whoever removes the event from the queue.
+                let text = text_from_ll(event.text);
+                if owned {
+                    #[cfg(feature = "log")] debug!("Calling 'SDL_free'");
+                    SDL_free(event.text as _);
+                }
+
+                Event::TextEditing { timestamp, window_id: event.window_id, text, start: event.start, length: event.length }
+            }
+            EventType::TextInput => {
+                let event = raw.text;
+                Event::TextInput {
+                    timestamp,
+                    window_id: event.windowID,
+                    text: text_from_ll(event.text.as_ptr())
+                }
+            }
+            EventType::KeymapChanged => Event::KeymapChanged { timestamp },
+
+            EventType::MouseMotion => {
+                let event = raw.motion;
+                Event::MouseMotion {
+                    timestamp,
+                    window_id: event.windowID,
+                    which: event.which,
+                    mousestate: MouseButtons::from(event.state),
+                    x: event.x,
+                    y: event.y,
+                    xrel: event.xrel,
+                    yrel: event.yrel
+                }
+            }
+            EventType::MouseButtonDown | EventType::MouseButtonUp => {
+                let event = raw.button;
+                let (window_id, which, mouse_btn, clicks, x, y) = (
+                    event.windowID,
+                    event.which,
+                    MouseButton::from_ll(event.button),
+                    event.clicks,
+                    event.x,
+                    event.y
+                );
+
+                if event_type == EventType::MouseButtonDown {
+                    Event::MouseButtonDown { timestamp, window_id, which, mouse_btn, clicks, x, y }
+                } else {
+                    Event::MouseButtonUp { timestamp, window_id, which, mouse_btn, clicks, x, y }
+                }
+            }
+            EventType::MouseWheel => {
+                let event = raw.wheel;
+                Event::MouseWheel {
+                    timestamp,
+                    window_id: event.windowID,
+                    which: event.which,
+                    x: event.x,
+                    y: event.y,
+                    direction: if event.direction == SDL_MouseWheelDirection::SDL_MOUSEWHEEL_FLIPPED as u32 {
+                        MouseWheelDirection::Flipped
+                    } else {
+                        MouseWheelDirection::Normal
+                    }
+                }
+            }
+
+            EventType::JoyAxisMotion => {
+                let event = raw.jaxis;
+                Event::JoyAxisMotion { timestamp, which: event.which, axis_idx: event.axis, value: event.value }
+            }
+            EventType::JoyBallMotion => {
+                let event = raw.jball;
+                Event::JoyBallMotion { timestamp, which: event.which, ball_idx: event.ball, xrel: event.xrel, yrel: event.yrel }
+            }
+            EventType::JoyHatMotion => {
+                let event = raw.jhat;
+                Event::JoyHatMotion { timestamp, which: event.which, hat_idx: event.hat, value: HatState::from(event.value) }
+            }
+            EventType::JoyButtonDown => {
+                let event = raw.jbutton;
+                Event::JoyButtonDown { timestamp, which: event.which, button_idx: event.button }
+            }
+            EventType::JoyButtonUp => {
+                let event = raw.jbutton;
+                Event::JoyButtonUp { timestamp, which: event.which, button_idx: event.button }
+            }
+            EventType::JoyDeviceAdded => Event::JoyDeviceAdded { timestamp, which: raw.jdevice.which as _ },
+            EventType::JoyDeviceRemoved => Event::JoyDeviceRemoved { timestamp, which: raw.jdevice.which },
+
+            EventType::ControllerAxisMotion => {
+                let event = raw.caxis;
+                Event::ControllerAxisMotion { timestamp, which: event.which, axis_idx: event.axis, value: event.value }
+            }
+            EventType::ControllerButtonDown => {
+                let event = raw.cbutton;
+                Event::ControllerButtonDown { timestamp, which: event.which, button_idx: event.button }
+            }
+            EventType::ControllerButtonUp => {
+                let event = raw.cbutton;
+                Event::ControllerButtonUp { timestamp, which: event.which, button_idx: event.button }
+            }
+            EventType::ControllerDeviceAdded => Event::ControllerDeviceAdded { timestamp, which: raw.cdevice.which as _ },
+            EventType::ControllerDeviceRemoved => Event::ControllerDeviceRemoved { timestamp, which: raw.cdevice.which },
+            EventType::ControllerDeviceRemapped => Event::ControllerDeviceRemapped { timestamp, which: raw.cdevice.which },
+            EventType::ControllerTouchpadDown | EventType::ControllerTouchpadMotion | EventType::ControllerTouchpadUp => {
+                let event = raw.ctouchpad;
+                let (which, touchpad, finger, x, y, pressure) = (
+                    event.which,
+                    event.touchpad,
+                    event.finger,
+                    event.x,
+                    event.y,
+                    event.pressure
+                );
+
+                match event_type {
+                    EventType::ControllerTouchpadDown => Event::ControllerTouchpadDown { timestamp, which, touchpad, finger, x, y, pressure },
+                    EventType::ControllerTouchpadMotion => Event::ControllerTouchpadMotion { timestamp, which, touchpad, finger, x, y, pressure },
+                    _ => Event::ControllerTouchpadUp { timestamp, which, touchpad, finger, x, y, pressure }
+                }
+            }
+            EventType::ControllerSensorUpdate => {
+                let event = raw.csensor;
+                Event::ControllerSensorUpdate { timestamp, which: event.which, sensor: event.sensor, data: event.data }
+            }
+
+            EventType::FingerDown | EventType::FingerMotion | EventType::FingerUp => {
+                let event = raw.tfinger;
+                let (touch_id, finger_id, x, y, dx, dy, pressure, window_id) = (
+                    event.touchId,
+                    event.fingerId,
+                    event.x,
+                    event.y,
+                    event.dx,
+                    event.dy,
+                    event.pressure,
+                    event.windowID
+                );
+
+                match event_type {
+                    EventType::FingerDown => Event::FingerDown { timestamp, touch_id, finger_id, x, y, dx, dy, pressure, window_id },
+                    EventType::FingerMotion => Event::FingerMotion { timestamp, touch_id, finger_id, x, y, dx, dy, pressure, window_id },
+                    _ => Event::FingerUp { timestamp, touch_id, finger_id, x, y, dx, dy, pressure, window_id }
+                }
+            }
+
+            EventType::DollarGesture | EventType::DollarRecord => {
+                let event = raw.dgesture;
+                let (touch_id, gesture_id, num_fingers, error, x, y) = (
+                    event.touchId,
+                    event.gestureId,
+                    event.numFingers,
+                    event.error,
+                    event.x,
+                    event.y
+                );
+
+                if event_type == EventType::DollarGesture {
+                    Event::DollarGesture { timestamp, touch_id, gesture_id, num_fingers, error, x, y }
+                } else {
+                    Event::DollarRecord { timestamp, touch_id, gesture_id, num_fingers, error, x, y }
+                }
+            }
+            EventType::MultiGesture => {
+                let event = raw.mgesture;
+                Event::MultiGesture {
+                    timestamp,
+                    touch_id: event.touchId,
+                    d_theta: event.dTheta,
+                    d_dist: event.dDist,
+                    x: event.x,
+                    y: event.y,
+                    num_fingers: event.numFingers
+                }
+            }
+
+            EventType::ClipboardUpdate => Event::ClipboardUpdate { timestamp },
+
+            EventType::DropFile | EventType::DropText => {
+                let event = raw.drop;
+
+                // The file name or text of drop events is allocated by SDL, and must
+                // be freed by whoever removes the event from the queue.
+                let text = text_from_ll(event.file);
+                if owned {
+                    #[cfg(feature = "log")] debug!("Calling 'SDL_free'");
+                    SDL_free(event.file as _);
+                }
+
+                if event_type == EventType::DropFile {
+                    Event::DropFile { timestamp, window_id: event.windowID, filename: text }
+                } else {
+                    Event::DropText { timestamp, window_id: event.windowID, text }
+                }
+            }
+            EventType::DropBegin => Event::DropBegin { timestamp, window_id: raw.drop.windowID },
+            EventType::DropComplete => Event::DropComplete { timestamp, window_id: raw.drop.windowID },
+
+            EventType::AudioDeviceAdded => {
+                let event = raw.adevice;
+                Event::AudioDeviceAdded { timestamp, which: event.which, iscapture: event.iscapture != 0 }
+            }
+            EventType::AudioDeviceRemoved => {
+                let event = raw.adevice;
+                Event::AudioDeviceRemoved { timestamp, which: event.which, iscapture: event.iscapture != 0 }
+            }
+
+            EventType::SensorUpdate => {
+                let event = raw.sensor;
+                Event::SensorUpdate { timestamp, which: event.which, data: event.data }
+            }
+
+            EventType::RenderTargetsReset => Event::RenderTargetsReset { timestamp },
+            EventType::RenderDeviceReset => Event::RenderDeviceReset { timestamp },
+
+            EventType::User if is_custom_event_type(type_) && !raw.user.data1.is_null() => {
+                Event::Custom {
+                    timestamp,
+                    type_,
+                    data: if owned {
+                        CustomEventData::from_ll(raw.user.data1)
+                    } else {
+                        CustomEventData::borrow_ll(raw.user.data1)
+                    }
+                }
+            }
+            EventType::User => {
+                let event = raw.user;
+                Event::User {
+                    timestamp,
+                    window_id: event.windowID,
+                    type_,
+                    code: event.code,
+                    data1: event.data1,
+                    data2: event.data2
+                }
+            }
+
+            _ => Event::Unknown { timestamp, type_ }
+        }
+    }
+
+    /// Get the time at which SDL created this event, in milliseconds.
+    pub fn timestamp(&self) -> u32 {
+        match *self {
+            Event::Quit { timestamp } |
+            Event::AppTerminating { timestamp } |
+            Event::AppLowMemory { timestamp } |
+            Event::AppWillEnterBackground { timestamp } |
+            Event::AppDidEnterBackground { timestamp } |
+            Event::AppWillEnterForeground { timestamp } |
+            Event::AppDidEnterForeground { timestamp } |
+            Event::LocaleChanged { timestamp } |
+            Event::Display { timestamp, .. } |
+            Event::Window { timestamp, .. } |
+            Event::KeyDown { timestamp, .. } |
+            Event::KeyUp { timestamp, .. } |
+            Event::TextEditing { timestamp, .. } |
+            Event::TextInput { timestamp, .. } |
+            Event::KeymapChanged { timestamp } |
+            Event::MouseMotion { timestamp, .. } |
+            Event::MouseButtonDown { timestamp, .. } |
+            Event::MouseButtonUp { timestamp, .. } |
+            Event::MouseWheel { timestamp, .. } |
+            Event::JoyAxisMotion { timestamp, .. } |
+            Event::JoyBallMotion { timestamp, .. } |
+            Event::JoyHatMotion { timestamp, .. } |
+            Event::JoyButtonDown { timestamp, .. } |
+            Event::JoyButtonUp { timestamp, .. } |
+            Event::JoyDeviceAdded { timestamp, .. } |
+            Event::JoyDeviceRemoved { timestamp, .. } |
+            Event::ControllerAxisMotion { timestamp, .. } |
+            Event::ControllerButtonDown { timestamp, .. } |
+            Event::ControllerButtonUp { timestamp, .. } |
+            Event::ControllerDeviceAdded { timestamp, .. } |
+            Event::ControllerDeviceRemoved { timestamp, .. } |
+            Event::ControllerDeviceRemapped { timestamp, .. } |
+            Event::ControllerTouchpadDown { timestamp, .. } |
+            Event::ControllerTouchpadMotion { timestamp, .. } |
+            Event::ControllerTouchpadUp { timestamp, .. } |
+            Event::ControllerSensorUpdate { timestamp, .. } |
+            Event::FingerDown { timestamp, .. } |
+            Event::FingerUp { timestamp, .. } |
+            Event::FingerMotion { timestamp, .. } |
+            Event::DollarGesture { timestamp, .. } |
+            Event::DollarRecord { timestamp, .. } |
+            Event::MultiGesture { timestamp, .. } |
+            Event::ClipboardUpdate { timestamp } |
+            Event::DropFile { timestamp, .. } |
+            Event::DropText { timestamp, .. } |
+            Event::DropBegin { timestamp, .. } |
+            Event::DropComplete { timestamp, .. } |
+            Event::AudioDeviceAdded { timestamp, .. } |
+            Event::AudioDeviceRemoved { timestamp, .. } |
+            Event::SensorUpdate { timestamp, .. } |
+            Event::RenderTargetsReset { timestamp } |
+            Event::RenderDeviceReset { timestamp } |
+            Event::User { timestamp, .. } |
+            Event::Custom { timestamp, .. } |
+            Event::Unknown { timestamp, .. } => timestamp
+        }
+    }
+
+    /// Returns [`SdlError::InvalidValue`] if this event cannot be safely converted into
+    /// a raw SDL event, as SDL or [`Event::from_ll`] would read its fields as pointers.
+    /// 
+    /// This is the case for:
+    /// - [`Event::User`] using a type below [`EventType::User`], or one registered for custom events.
+    /// - [`Event::Custom`] using a type not registered for custom events.
+    /// - [`Event::Unknown`] using a type whose event holds a pointer.
+    pub(crate) fn check_pushable(&self) -> Result<(), SdlError> {
+        match *self {
+            Event::User { type_, .. } if type_ < EventType::User as u32 => Err(SdlError::InvalidValue(format!(
+                "Event type {} is not a user event type.", type_
+            ))),
+            Event::User { type_, .. } if is_custom_event_type(type_) => Err(SdlError::InvalidValue(format!(
+                "Event type {} is registered for custom events, use 'Event::Custom' instead.", type_
+            ))),
+            Event::Custom { type_, .. } if !is_custom_event_type(type_) => Err(SdlError::InvalidValue(format!(
+                "Event type {} is not registered for custom events.", type_
+            ))),
+            Event::Unknown { type_, .. } if holds_pointer(type_) => Err(SdlError::InvalidValue(format!(
+                "Events of type {} cannot be pushed without their data.", type_
+            ))),
+            _ => Ok(())
+        }
+    }
+
+    /// Convert this event into a raw SDL event, transferring ownership of any
+    /// owned data (such as custom event payloads) to the raw event.
+    /// 
+    /// The timestamp is kept, but is overwritten by SDL when the event is pushed.
+    pub(crate) fn into_ll(self) -> SDL_Event {
+        let mut raw: SDL_Event = unsafe { mem::zeroed() };
+
+        match self {
+            Event::Quit { timestamp } => raw.quit = SDL_QuitEvent { type_: EventType::Quit as _, timestamp },
+
+            Event::AppTerminating { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::AppTerminating as _, timestamp },
+            Event::AppLowMemory { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::AppLowMemory as _, timestamp },
+            Event::AppWillEnterBackground { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::AppWillEnterBackground as _, timestamp },
+            Event::AppDidEnterBackground { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::AppDidEnterBackground as _, timestamp },
+            Event::AppWillEnterForeground { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::AppWillEnterForeground as _, timestamp },
+            Event::AppDidEnterForeground { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::AppDidEnterForeground as _, timestamp },
+            Event::LocaleChanged { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::LocaleChanged as _, timestamp },
+
+            Event::Display { timestamp, display_index, display_event } => {
+                let (event, data1) = match display_event {
+                    DisplayEvent::None => (SDL_DisplayEventID::SDL_DISPLAYEVENT_NONE, 0),
+                    DisplayEvent::Orientation(orientation) => (SDL_DisplayEventID::SDL_DISPLAYEVENT_ORIENTATION, orientation),
+                    DisplayEvent::Connected => (SDL_DisplayEventID::SDL_DISPLAYEVENT_CONNECTED, 0),
+                    DisplayEvent::Disconnected => (SDL_DisplayEventID::SDL_DISPLAYEVENT_DISCONNECTED, 0)
+                };
+
+                raw.display = SDL_DisplayEvent {
+                    type_: EventType::Display as _,
+                    timestamp,
+                    display: display_index,
+                    event: event as _,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
+                    data1
+                };
+            }
+            Event::Window { timestamp, window_id, win_event } => {
+                use SDL_WindowEventID::*;
+
+                let (event, data1, data2) = match win_event {
+                    WindowEvent::None => (SDL_WINDOWEVENT_NONE, 0, 0),
+                    WindowEvent::Shown => (SDL_WINDOWEVENT_SHOWN, 0, 0),
+                    WindowEvent::Hidden => (SDL_WINDOWEVENT_HIDDEN, 0, 0),
+                    WindowEvent::Exposed => (SDL_WINDOWEVENT_EXPOSED, 0, 0),
+                    WindowEvent::Moved(x, y) => (SDL_WINDOWEVENT_MOVED, x, y),
+                    WindowEvent::Resized(w, h) => (SDL_WINDOWEVENT_RESIZED, w, h),
+                    WindowEvent::SizeChanged(w, h) => (SDL_WINDOWEVENT_SIZE_CHANGED, w, h),
+                    WindowEvent::Minimized => (SDL_WINDOWEVENT_MINIMIZED, 0, 0),
+                    WindowEvent::Maximized => (SDL_WINDOWEVENT_MAXIMIZED, 0, 0),
+                    WindowEvent::Restored => (SDL_WINDOWEVENT_RESTORED, 0, 0),
+                    WindowEvent::Enter => (SDL_WINDOWEVENT_ENTER, 0, 0),
+                    WindowEvent::Leave => (SDL_WINDOWEVENT_LEAVE, 0, 0),
+                    WindowEvent::FocusGained => (SDL_WINDOWEVENT_FOCUS_GAINED, 0, 0),
+                    WindowEvent::FocusLost => (SDL_WINDOWEVENT_FOCUS_LOST, 0, 0),
+                    WindowEvent::Close => (SDL_WINDOWEVENT_CLOSE, 0, 0),
+                    WindowEvent::TakeFocus => (SDL_WINDOWEVENT_TAKE_FOCUS, 0, 0),
+                    WindowEvent::HitTest => (SDL_WINDOWEVENT_HIT_TEST, 0, 0)
+                };
+
+                raw.window = SDL_WindowEvent {
+                    type_: EventType::Window as _,
+                    timestamp,
+                    windowID: window_id,
+                    event: event as _,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
+                    data1,
+                    data2
+                };
+            }
+
+            Event::KeyDown { timestamp, window_id, keycode, scancode, keymod, repeat } |
+            Event::KeyUp { timestamp, window_id, keycode, scancode, keymod, repeat } => {
+                let (type_, state) = if matches!(self, Event::KeyDown { .. }) {
+                    (EventType::KeyDown, SDL_PRESSED)
+                } else {
+                    (EventType::KeyUp, SDL_RELEASED)
+                };
+
+                raw.key = SDL_KeyboardEvent {
+                    type_: type_ as _,
+                    timestamp,
+                    windowID: window_id,
+                    state: state as _,
+                    repeat: repeat as _,
+                    padding2: 0,
+                    padding3: 0,
+                    keysym: SDL_Keysym {
+                        scancode: SDL_Scancode::SDL_SCANCODE_UNKNOWN,
+                        sym: keycode.0,
+                        mod_: keymod.bits(),
+                        unused: 0
+                    }
+                };
+
+                // `SDL_Scancode` is a Rust enum, so scancodes unknown to the bindings
+                // are written as their raw value.
+                unsafe { ptr::addr_of_mut!(raw.key.keysym.scancode).cast::<u32>().write(scancode.0) };
+            }
+            // Text that does not fit into the fixed size buffer uses the extended event.
+            Event::TextEditing { timestamp, window_id, text, start, length } if text.len() >= SDL_TEXTEDITINGEVENT_TEXT_SIZE as usize => {
+                let event = SDL_TextEditingExtEvent {
+                    type_: EventType::TextEditingExt as _,
+                    timestamp,
+                    window_id,
+                    text: text_to_sdl_alloc(&text),
+                    start,
+                    length
+                };
+                unsafe { ptr::from_mut(&mut raw).cast::<SDL_TextEditingExtEvent>().write(event) };
+            }
+            Event::TextEditing { timestamp, window_id, text, start, length } => {
+                raw.edit = SDL_TextEditingEvent {
+                    type_: EventType::TextEditing as _,
+                    timestamp,
+                    windowID: window_id,
+                    text: text_to_ll(&text),
+                    start,
+                    length
+                };
+            }
+            Event::TextInput { timestamp, window_id, text } => {
+                raw.text = SDL_TextInputEvent {
+                    type_: EventType::TextInput as _,
+                    timestamp,
+                    windowID: window_id,
+                    text: text_to_ll(&text)
+                };
+            }
+            Event::KeymapChanged { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::KeymapChanged as _, timestamp },
+
+            Event::MouseMotion { timestamp, window_id, which, mousestate, x, y, xrel, yrel } => {
+                raw.motion = SDL_MouseMotionEvent {
+                    type_: EventType::MouseMotion as _,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    state: mousestate.bits(),
+                    x,
+                    y,
+                    xrel,
+                    yrel
+                };
+            }
+            Event::MouseButtonDown { timestamp, window_id, which, mouse_btn, clicks, x, y } |
+            Event::MouseButtonUp { timestamp, window_id, which, mouse_btn, clicks, x, y } => {
+                let (type_, state) = if matches!(self, Event::MouseButtonDown { .. }) {
+                    (EventType::MouseButtonDown, SDL_PRESSED)
+                } else {
+                    (EventType::MouseButtonUp, SDL_RELEASED)
+                };
+
+                raw.button = SDL_MouseButtonEvent {
+                    type_: type_ as _,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    button: mouse_btn.to_ll(),
+                    state: state as _,
+                    clicks,
+                    padding1: 0,
+                    x,
+                    y
+                };
+            }
+            Event::MouseWheel { timestamp, window_id, which, x, y, direction } => {
+                raw.wheel = SDL_MouseWheelEvent {
+                    type_: EventType::MouseWheel as _,
+                    timestamp,
+                    windowID: window_id,
+                    which,
+                    x,
+                    y,
+                    direction: match direction {
+                        MouseWheelDirection::Normal => SDL_MouseWheelDirection::SDL_MOUSEWHEEL_NORMAL as _,
+                        MouseWheelDirection::Flipped => SDL_MouseWheelDirection::SDL_MOUSEWHEEL_FLIPPED as _
+                    }
+                };
+            }
+
+            Event::JoyAxisMotion { timestamp, which, axis_idx, value } => {
+                raw.jaxis = SDL_JoyAxisEvent {
+                    type_: EventType::JoyAxisMotion as _,
+                    timestamp,
+                    which,
+                    axis: axis_idx,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
+                    value,
+                    padding4: 0
+                };
+            }
+            Event::JoyBallMotion { timestamp, which, ball_idx, xrel, yrel } => {
+                raw.jball = SDL_JoyBallEvent {
+                    type_: EventType::JoyBallMotion as _,
+                    timestamp,
+                    which,
+                    ball: ball_idx,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
+                    xrel,
+                    yrel
+                };
+            }
+            Event::JoyHatMotion { timestamp, which, hat_idx, value } => {
+                raw.jhat = SDL_JoyHatEvent {
+                    type_: EventType::JoyHatMotion as _,
+                    timestamp,
+                    which,
+                    hat: hat_idx,
+                    value: value.bits(),
+                    padding1: 0,
+                    padding2: 0
+                };
+            }
+            Event::JoyButtonDown { timestamp, which, button_idx } |
+            Event::JoyButtonUp { timestamp, which, button_idx } => {
+                let (type_, state) = if matches!(self, Event::JoyButtonDown { .. }) {
+                    (EventType::JoyButtonDown, SDL_PRESSED)
+                } else {
+                    (EventType::JoyButtonUp, SDL_RELEASED)
+                };
+
+                raw.jbutton = SDL_JoyButtonEvent {
+                    type_: type_ as _,
+                    timestamp,
+                    which,
+                    button: button_idx,
+                    state: state as _,
+                    padding1: 0,
+                    padding2: 0
+                };
+            }
+            Event::JoyDeviceAdded { timestamp, which } => {
+                raw.jdevice = SDL_JoyDeviceEvent { type_: EventType::JoyDeviceAdded as _, timestamp, which: which as _ };
+            }
+            Event::JoyDeviceRemoved { timestamp, which } => {
+                raw.jdevice = SDL_JoyDeviceEvent { type_: EventType::JoyDeviceRemoved as _, timestamp, which };
+            }
+
+            Event::ControllerAxisMotion { timestamp, which, axis_idx, value } => {
+                raw.caxis = SDL_ControllerAxisEvent {
+                    type_: EventType::ControllerAxisMotion as _,
+                    timestamp,
+                    which,
+                    axis: axis_idx,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
+                    value,
+                    padding4: 0
+                };
+            }
+            Event::ControllerButtonDown { timestamp, which, button_idx } |
+            Event::ControllerButtonUp { timestamp, which, button_idx } => {
+                let (type_, state) = if matches!(self, Event::ControllerButtonDown { .. }) {
+                    (EventType::ControllerButtonDown, SDL_PRESSED)
+                } else {
+                    (EventType::ControllerButtonUp, SDL_RELEASED)
+                };
+
+                raw.cbutton = SDL_ControllerButtonEvent {
+                    type_: type_ as _,
+                    timestamp,
+                    which,
+                    button: button_idx,
+                    state: state as _,
+                    padding1: 0,
+                    padding2: 0
+                };
+            }
+            Event::ControllerDeviceAdded { timestamp, which } => {
+                raw.cdevice = SDL_ControllerDeviceEvent { type_: EventType::ControllerDeviceAdded as _, timestamp, which: which as _ };
+            }
+            Event::ControllerDeviceRemoved { timestamp, which } => {
+                raw.cdevice = SDL_ControllerDeviceEvent { type_: EventType::ControllerDeviceRemoved as _, timestamp, which };
+            }
+            Event::ControllerDeviceRemapped { timestamp, which } => {
+                raw.cdevice = SDL_ControllerDeviceEvent { type_: EventType::ControllerDeviceRemapped as _, timestamp, which };
+            }
+            Event::ControllerTouchpadDown { timestamp, which, touchpad, finger, x, y, pressure } |
+            Event::ControllerTouchpadMotion { timestamp, which, touchpad, finger, x, y, pressure } |
+            Event::ControllerTouchpadUp { timestamp, which, touchpad, finger, x, y, pressure } => {
+                let type_ = match self {
+                    Event::ControllerTouchpadDown { .. } => EventType::ControllerTouchpadDown,
+                    Event::ControllerTouchpadMotion { .. } => EventType::ControllerTouchpadMotion,
+                    _ => EventType::ControllerTouchpadUp
+                };
+
+                raw.ctouchpad = SDL_ControllerTouchpadEvent { type_: type_ as _, timestamp, which, touchpad, finger, x, y, pressure };
+            }
+            Event::ControllerSensorUpdate { timestamp, which, sensor, data } => {
+                raw.csensor = SDL_ControllerSensorEvent { type_: EventType::ControllerSensorUpdate as _, timestamp, which, sensor, data };
+            }
+
+            Event::FingerDown { timestamp, touch_id, finger_id, x, y, dx, dy, pressure, window_id } |
+            Event::FingerUp { timestamp, touch_id, finger_id, x, y, dx, dy, pressure, window_id } |
+            Event::FingerMotion { timestamp, touch_id, finger_id, x, y, dx, dy, pressure, window_id } => {
+                let type_ = match self {
+                    Event::FingerDown { .. } => EventType::FingerDown,
+                    Event::FingerMotion { .. } => EventType::FingerMotion,
+                    _ => EventType::FingerUp
+                };
+
+                raw.tfinger = SDL_TouchFingerEvent {
+                    type_: type_ as _,
+                    timestamp,
+                    touchId: touch_id,
+                    fingerId: finger_id,
+                    x,
+                    y,
+                    dx,
+                    dy,
+                    pressure,
+                    windowID: window_id
+                };
+            }
+
+            Event::DollarGesture { timestamp, touch_id, gesture_id, num_fingers, error, x, y } |
+            Event::DollarRecord { timestamp, touch_id, gesture_id, num_fingers, error, x, y } => {
+                let type_ = if matches!(self, Event::DollarGesture { .. }) {
+                    EventType::DollarGesture
+                } else {
+                    EventType::DollarRecord
+                };
+
+                raw.dgesture = SDL_DollarGestureEvent {
+                    type_: type_ as _,
+                    timestamp,
+                    touchId: touch_id,
+                    gestureId: gesture_id,
+                    numFingers: num_fingers,
+                    error,
+                    x,
+                    y
+                };
+            }
+            Event::MultiGesture { timestamp, touch_id, d_theta, d_dist, x, y, num_fingers } => {
+                raw.mgesture = SDL_MultiGestureEvent {
+                    type_: EventType::MultiGesture as _,
+                    timestamp,
+                    touchId: touch_id,
+                    dTheta: d_theta,
+                    dDist: d_dist,
+                    x,
+                    y,
+                    numFingers: num_fingers,
+                    padding: 0
+                };
+            }
+
+            Event::ClipboardUpdate { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::ClipboardUpdate as _, timestamp },
+
+            Event::DropFile { timestamp, window_id, ref filename } => {
+                raw.drop = SDL_DropEvent { type_: EventType::DropFile as _, timestamp, file: text_to_sdl_alloc(filename), windowID: window_id };
+            }
+            Event::DropText { timestamp, window_id, ref text } => {
+                raw.drop = SDL_DropEvent { type_: EventType::DropText as _, timestamp, file: text_to_sdl_alloc(text), windowID: window_id };
+            }
+            Event::DropBegin { timestamp, window_id } => {
+                raw.drop = SDL_DropEvent { type_: EventType::DropBegin as _, timestamp, file: ptr::null_mut(), windowID: window_id };
+            }
+            Event::DropComplete { timestamp, window_id } => {
+                raw.drop = SDL_DropEvent { type_: EventType::DropComplete as _, timestamp, file: ptr::null_mut(), windowID: window_id };
+            }
+
+            Event::AudioDeviceAdded { timestamp, which, iscapture } |
+            Event::AudioDeviceRemoved { timestamp, which, iscapture } => {
+                let type_ = if matches!(self, Event::AudioDeviceAdded { .. }) {
+                    EventType::AudioDeviceAdded
+                } else {
+                    EventType::AudioDeviceRemoved
+                };
+
+                raw.adevice = SDL_AudioDeviceEvent {
+                    type_: type_ as _,
+                    timestamp,
+                    which,
+                    iscapture: iscapture as _,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0
+                };
+            }
+
+            Event::SensorUpdate { timestamp, which, data } => {
+                raw.sensor = SDL_SensorEvent { type_: EventType::SensorUpdate as _, timestamp, which, data };
+            }
+
+            Event::RenderTargetsReset { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::RenderTargetsReset as _, timestamp },
+            Event::RenderDeviceReset { timestamp } => raw.common = SDL_CommonEvent { type_: EventType::RenderDeviceReset as _, timestamp },
+
+            Event::User { timestamp, window_id, type_, code, data1, data2 } => {
+                raw.user = SDL_UserEvent { type_, timestamp, windowID: window_id, code, data1, data2 };
+            }
+            Event::Custom { timestamp, type_, data } => {
+                raw.user = SDL_UserEvent { type_, timestamp, windowID: 0, code: 0, data1: data.into_ll(), data2: ptr::null_mut() };
+            }
+
+            Event::Unknown { timestamp, type_ } => raw.common = SDL_CommonEvent { type_, timestamp }
+        }
+
+        raw
+    }
+
+    /// Get a reference to the value carried by a custom event, if this is a
+    /// custom event carrying a `T`.
+    /// 
+    /// See [`SdlSubsystem<Events>::push_custom_event`](crate::subsystems::SdlSubsystem::push_custom_event).
+    pub fn as_user_event<T: 'static>(&self) -> Option<&T> {
+        match self {
+            Event::Custom { data, .. } => data.downcast_ref(),
+            _ => Option::None
+        }
+    }
+
+    /// Take the value carried by a custom event, if this is a custom event carrying a `T`.
+    /// 
+    /// Returns the event unchanged if it does not carry a `T`, or if it only
+    /// borrows its value (as with events passed to filters and watchers).
+    pub fn into_user_event<T: 'static>(self) -> Result<T, Event> {
+        match self {
+            Event::Custom { timestamp, type_, data: CustomEventData(CustomPayload::Owned(value)) } => match value.downcast::<T>() {
+                Ok(value) => Ok(*value),
+                Err(value) => Err(Event::Custom { timestamp, type_, data: CustomEventData(CustomPayload::Owned(value)) })
+            },
+            event => Err(event)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::text_to_ll;
+
+    fn ll_to_bytes(buffer: &[std::ffi::c_char; 32]) -> Vec<u8> {
+        buffer.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect()
+    }
+
+    #[test]
+    fn text_to_ll_copies_short_text() {
+        let buffer = text_to_ll("hello");
+        assert_eq!(ll_to_bytes(&buffer), b"hello");
+        assert!(buffer[5..].iter().all(|&c| c == 0));
+    }
+
+    #[test]
+    fn text_to_ll_truncates_to_31_bytes() {
+        let buffer = text_to_ll(&"a".repeat(40));
+        assert_eq!(ll_to_bytes(&buffer), "a".repeat(31).as_bytes());
+        assert_eq!(buffer[31], 0);
+    }
+
+    #[test]
+    fn text_to_ll_truncates_at_char_boundary() {
+        // 30 ASCII bytes followed by a 2 byte character, which would end at byte 32.
+        let text = format!("{}é", "a".repeat(30));
+        assert_eq!(ll_to_bytes(&text_to_ll(&text)), "a".repeat(30).as_bytes());
+
+        // 10 characters of 3 bytes each fit, the 11th does not.
+        let text = "€".repeat(11);
+        assert_eq!(ll_to_bytes(&text_to_ll(&text)), "€".repeat(10).as_bytes());
+    }
+}
@@ -0,0 +1,143 @@
+use std::{ffi::{c_int, c_void}, marker::PhantomData, sync::Mutex, thread::{self, ThreadId}};
+use crate::sys::*;
+use crate::subsystems::{SdlSubsystem, markers::Events};
+use super::{event::{Event, EventType}, discard_events};
+
+type EventFilter = Box<dyn FnMut(&Event) -> bool + Send>;
+
+/// The filter currently installed via [`SdlSubsystem<Events>::set_event_filter`].
+/// 
+/// SDL holds a pointer to the inner box, so it must only be dropped after SDL stops using it.
+static EVENT_FILTER: Mutex<Option<Box<EventFilter>>> = Mutex::new(Option::None);
+
+unsafe extern "C" fn event_filter_callback(userdata: *mut c_void, raw: *mut SDL_Event) -> c_int {
+    let filter = &mut *(userdata as *mut EventFilter);
+    let keep = filter(&Event::from_ll_borrowed(&*raw));
+
+    if !keep {
+        // SDL discards the event without freeing anything it points to.
+        drop(Event::from_ll(*raw));
+    }
+
+    keep as _
+}
+
+/// Data shared with SDL for a single event watcher.
+struct EventWatchData {
+    callback: Box<dyn FnMut(&Event)>,
+    thread: ThreadId
+}
+
+unsafe extern "C" fn event_watch_callback(userdata: *mut c_void, raw: *mut SDL_Event) -> c_int {
+    let data = &mut *(userdata as *mut EventWatchData);
+
+    // The callback is not required to be `Send`, so events dispatched
+    // on any other thread are not delivered to it.
+    if thread::current().id() == data.thread {
+        (data.callback)(&Event::from_ll_borrowed(&*raw));
+    }
+
+    0
+}
+
+unsafe extern "C" fn filter_events_callback(userdata: *mut c_void, raw: *mut SDL_Event) -> c_int {
+    let filter = &mut *(userdata as *mut &mut dyn FnMut(&Event) -> bool);
+    let keep = filter(&Event::from_ll_borrowed(&*raw));
+
+    if !keep {
+        // SDL discards the event without freeing anything it points to.
+        drop(Event::from_ll(*raw));
+    }
+
+    keep as _
+}
+
+/// An event watcher added via [`SdlSubsystem<Events>::add_event_watch`].
+/// 
+/// The watcher is removed when this is dropped.
+pub struct EventWatch<'a> {
+    data: *mut EventWatchData,
+    events: PhantomData<&'a SdlSubsystem<Events>>
+}
+
+impl Drop for EventWatch<'_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_DelEventWatch' via EventWatch drop.");
+        unsafe {
+            SDL_DelEventWatch(Some(event_watch_callback), self.data as _);
+            drop(Box::from_raw(self.data));
+        }
+    }
+}
+
+impl SdlSubsystem<Events> {
+
+    /// Set a filter which is called for every event before it is added to the
+    /// event queue. Events for which `filter` returns `false` are dropped.
+    /// 
+    /// This replaces any previously set filter. As the filter may be called from
+    /// any thread that pushes events, it must be `Send`.
+    /// 
+    /// Note: SDL discards all pending events when a filter is set.
+    #[doc(alias = "SDL_SetEventFilter")]
+    pub fn set_event_filter<F: FnMut(&Event) -> bool + Send + 'static>(&self, filter: F) {
+        let mut filter: Box<EventFilter> = Box::new(Box::new(filter));
+        let userdata = filter.as_mut() as *mut EventFilter;
+
+        let mut current = EVENT_FILTER.lock().unwrap();
+
+        // Drain the queue first, so that data owned by pending events is freed.
+        discard_events(EventType::First as _, EventType::Last as _);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetEventFilter'");
+        unsafe { SDL_SetEventFilter(Some(event_filter_callback), userdata as _) };
+
+        *current = Option::Some(filter);
+    }
+
+    /// Remove the filter set via [`SdlSubsystem<Events>::set_event_filter`].
+    /// 
+    /// Note: SDL discards all pending events when the filter is removed.
+    #[doc(alias = "SDL_SetEventFilter")]
+    pub fn clear_event_filter(&self) {
+        let mut current = EVENT_FILTER.lock().unwrap();
+
+        discard_events(EventType::First as _, EventType::Last as _);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetEventFilter'");
+        unsafe { SDL_SetEventFilter(Option::None, std::ptr::null_mut()) };
+
+        *current = Option::None;
+    }
+
+    /// Add a callback which is called for every event as it is added to the
+    /// event queue, after any event filter.
+    /// 
+    /// `callback` is only called for events added on the current thread, which
+    /// includes events generated by SDL while pumping the event loop.
+    /// 
+    /// The watcher is removed when the returned [`EventWatch`] is dropped.
+    #[doc(alias = "SDL_AddEventWatch")]
+    pub fn add_event_watch<F: FnMut(&Event) + 'static>(&self, callback: F) -> EventWatch<'_> {
+        let data = Box::into_raw(Box::new(EventWatchData {
+            callback: Box::new(callback),
+            thread: thread::current().id()
+        }));
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_AddEventWatch'");
+        unsafe { SDL_AddEventWatch(Some(event_watch_callback), data as _) };
+
+        EventWatch { data, events: PhantomData }
+    }
+
+    /// Run `filter` on every event in the event queue, removing those for which it returns `false`.
+    /// 
+    /// Any data owned by removed events is dropped.
+    #[doc(alias = "SDL_FilterEvents")]
+    pub fn filter_events<F: FnMut(&Event) -> bool>(&self, mut filter: F) {
+        let mut filter: &mut dyn FnMut(&Event) -> bool = &mut filter;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_FilterEvents'");
+        unsafe { SDL_FilterEvents(Some(filter_events_callback), &mut filter as *mut _ as _) };
+    }
+}
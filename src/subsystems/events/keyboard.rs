@@ -0,0 +1,91 @@
+use std::{mem, slice};
+use bitmask_enum::bitmask;
+use crate::{sys::*, subsystems::{SdlSubsystem, markers::Events}};
+use super::scancode::Scancode;
+
+/// Keyboard modifier keys.
+#[bitmask(u16)]
+pub enum Mod {
+    LShift = SDL_Keymod::KMOD_LSHIFT as _,
+    RShift = SDL_Keymod::KMOD_RSHIFT as _,
+    LCtrl = SDL_Keymod::KMOD_LCTRL as _,
+    RCtrl = SDL_Keymod::KMOD_RCTRL as _,
+    LAlt = SDL_Keymod::KMOD_LALT as _,
+    RAlt = SDL_Keymod::KMOD_RALT as _,
+    LGui = SDL_Keymod::KMOD_LGUI as _,
+    RGui = SDL_Keymod::KMOD_RGUI as _,
+    Num = SDL_Keymod::KMOD_NUM as _,
+    Caps = SDL_Keymod::KMOD_CAPS as _,
+    Mode = SDL_Keymod::KMOD_MODE as _,
+
+    Ctrl = SDL_Keymod::KMOD_CTRL as _,
+    Shift = SDL_Keymod::KMOD_SHIFT as _,
+    Alt = SDL_Keymod::KMOD_ALT as _,
+    Gui = SDL_Keymod::KMOD_GUI as _
+}
+
+/// A snapshot of which physical keys were pressed, see [`SdlSubsystem<Events>::keyboard_state`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardState {
+    state: Vec<u8>
+}
+
+impl KeyboardState {
+    /// Returns true if the given physical key was pressed.
+    pub fn is_scancode_pressed(&self, scancode: Scancode) -> bool {
+        self.state.get(scancode.0 as usize).is_some_and(|state| *state != 0)
+    }
+
+    /// Returns an iterator over all physical keys that were pressed.
+    pub fn pressed_scancodes(&self) -> impl Iterator<Item = Scancode> + '_ {
+        self.state.iter()
+            .enumerate()
+            .filter(|(_, state)| **state != 0)
+            .map(|(scancode, _)| Scancode(scancode as _))
+    }
+}
+
+impl SdlSubsystem<Events> {
+
+    /// Get a snapshot of the current state of the keyboard.
+    /// 
+    /// The state is updated as events are processed, so this reflects the
+    /// keyboard as of the last time the event loop was pumped.
+    #[doc(alias = "SDL_GetKeyboardState")]
+    pub fn keyboard_state(&self) -> KeyboardState {
+        let mut len = 0;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetKeyboardState'");
+        let state = unsafe { SDL_GetKeyboardState(&mut len) };
+
+        KeyboardState {
+            state: unsafe { slice::from_raw_parts(state, len as _) }.to_vec()
+        }
+    }
+
+    /// Get the current state of the modifier keys.
+    #[doc(alias = "SDL_GetModState")]
+    pub fn mod_state(&self) -> Mod {
+        // `SDL_Keymod` is a Rust enum in the bindings, but SDL returns combinations
+        // of its values, so the function is called with the raw value instead.
+        let get_mod_state: unsafe extern "C" fn() -> u32 = unsafe {
+            mem::transmute(SDL_GetModState as unsafe extern "C" fn() -> SDL_Keymod)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetModState'");
+        Mod::from(unsafe { get_mod_state() } as u16)
+    }
+
+    /// Set the current state of the modifier keys.
+    /// 
+    /// This does not change the state of the keyboard itself.
+    #[doc(alias = "SDL_SetModState")]
+    pub fn set_mod_state(&self, modstate: Mod) {
+        let set_mod_state: unsafe extern "C" fn(u32) = unsafe {
+            mem::transmute(SDL_SetModState as unsafe extern "C" fn(SDL_Keymod))
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetModState'");
+        unsafe { set_mod_state(modstate.bits() as _) }
+    }
+}
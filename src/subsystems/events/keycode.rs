@@ -0,0 +1,314 @@
+use std::{ffi::CString, fmt, mem};
+use crate::{sys::*, subsystems::{SdlSubsystem, markers::Events}};
+use super::scancode::Scancode;
+
+/// A virtual key, which depends on the current keyboard layout.
+/// 
+/// See [`Scancode`] for physical keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Keycode(pub i32);
+
+impl Keycode {
+    pub const UNKNOWN: Keycode = Keycode(SDL_KeyCode::SDLK_UNKNOWN as _);
+    pub const RETURN: Keycode = Keycode(SDL_KeyCode::SDLK_RETURN as _);
+    pub const ESCAPE: Keycode = Keycode(SDL_KeyCode::SDLK_ESCAPE as _);
+    pub const BACKSPACE: Keycode = Keycode(SDL_KeyCode::SDLK_BACKSPACE as _);
+    pub const TAB: Keycode = Keycode(SDL_KeyCode::SDLK_TAB as _);
+    pub const SPACE: Keycode = Keycode(SDL_KeyCode::SDLK_SPACE as _);
+    pub const EXCLAIM: Keycode = Keycode(SDL_KeyCode::SDLK_EXCLAIM as _);
+    pub const QUOTEDBL: Keycode = Keycode(SDL_KeyCode::SDLK_QUOTEDBL as _);
+    pub const HASH: Keycode = Keycode(SDL_KeyCode::SDLK_HASH as _);
+    pub const PERCENT: Keycode = Keycode(SDL_KeyCode::SDLK_PERCENT as _);
+    pub const DOLLAR: Keycode = Keycode(SDL_KeyCode::SDLK_DOLLAR as _);
+    pub const AMPERSAND: Keycode = Keycode(SDL_KeyCode::SDLK_AMPERSAND as _);
+    pub const QUOTE: Keycode = Keycode(SDL_KeyCode::SDLK_QUOTE as _);
+    pub const LEFTPAREN: Keycode = Keycode(SDL_KeyCode::SDLK_LEFTPAREN as _);
+    pub const RIGHTPAREN: Keycode = Keycode(SDL_KeyCode::SDLK_RIGHTPAREN as _);
+    pub const ASTERISK: Keycode = Keycode(SDL_KeyCode::SDLK_ASTERISK as _);
+    pub const PLUS: Keycode = Keycode(SDL_KeyCode::SDLK_PLUS as _);
+    pub const COMMA: Keycode = Keycode(SDL_KeyCode::SDLK_COMMA as _);
+    pub const MINUS: Keycode = Keycode(SDL_KeyCode::SDLK_MINUS as _);
+    pub const PERIOD: Keycode = Keycode(SDL_KeyCode::SDLK_PERIOD as _);
+    pub const SLASH: Keycode = Keycode(SDL_KeyCode::SDLK_SLASH as _);
+    pub const NUM_0: Keycode = Keycode(SDL_KeyCode::SDLK_0 as _);
+    pub const NUM_1: Keycode = Keycode(SDL_KeyCode::SDLK_1 as _);
+    pub const NUM_2: Keycode = Keycode(SDL_KeyCode::SDLK_2 as _);
+    pub const NUM_3: Keycode = Keycode(SDL_KeyCode::SDLK_3 as _);
+    pub const NUM_4: Keycode = Keycode(SDL_KeyCode::SDLK_4 as _);
+    pub const NUM_5: Keycode = Keycode(SDL_KeyCode::SDLK_5 as _);
+    pub const NUM_6: Keycode = Keycode(SDL_KeyCode::SDLK_6 as _);
+    pub const NUM_7: Keycode = Keycode(SDL_KeyCode::SDLK_7 as _);
+    pub const NUM_8: Keycode = Keycode(SDL_KeyCode::SDLK_8 as _);
+    pub const NUM_9: Keycode = Keycode(SDL_KeyCode::SDLK_9 as _);
+    pub const COLON: Keycode = Keycode(SDL_KeyCode::SDLK_COLON as _);
+    pub const SEMICOLON: Keycode = Keycode(SDL_KeyCode::SDLK_SEMICOLON as _);
+    pub const LESS: Keycode = Keycode(SDL_KeyCode::SDLK_LESS as _);
+    pub const EQUALS: Keycode = Keycode(SDL_KeyCode::SDLK_EQUALS as _);
+    pub const GREATER: Keycode = Keycode(SDL_KeyCode::SDLK_GREATER as _);
+    pub const QUESTION: Keycode = Keycode(SDL_KeyCode::SDLK_QUESTION as _);
+    pub const AT: Keycode = Keycode(SDL_KeyCode::SDLK_AT as _);
+    pub const LEFTBRACKET: Keycode = Keycode(SDL_KeyCode::SDLK_LEFTBRACKET as _);
+    pub const BACKSLASH: Keycode = Keycode(SDL_KeyCode::SDLK_BACKSLASH as _);
+    pub const RIGHTBRACKET: Keycode = Keycode(SDL_KeyCode::SDLK_RIGHTBRACKET as _);
+    pub const CARET: Keycode = Keycode(SDL_KeyCode::SDLK_CARET as _);
+    pub const UNDERSCORE: Keycode = Keycode(SDL_KeyCode::SDLK_UNDERSCORE as _);
+    pub const BACKQUOTE: Keycode = Keycode(SDL_KeyCode::SDLK_BACKQUOTE as _);
+    pub const A: Keycode = Keycode(SDL_KeyCode::SDLK_a as _);
+    pub const B: Keycode = Keycode(SDL_KeyCode::SDLK_b as _);
+    pub const C: Keycode = Keycode(SDL_KeyCode::SDLK_c as _);
+    pub const D: Keycode = Keycode(SDL_KeyCode::SDLK_d as _);
+    pub const E: Keycode = Keycode(SDL_KeyCode::SDLK_e as _);
+    pub const F: Keycode = Keycode(SDL_KeyCode::SDLK_f as _);
+    pub const G: Keycode = Keycode(SDL_KeyCode::SDLK_g as _);
+    pub const H: Keycode = Keycode(SDL_KeyCode::SDLK_h as _);
+    pub const I: Keycode = Keycode(SDL_KeyCode::SDLK_i as _);
+    pub const J: Keycode = Keycode(SDL_KeyCode::SDLK_j as _);
+    pub const K: Keycode = Keycode(SDL_KeyCode::SDLK_k as _);
+    pub const L: Keycode = Keycode(SDL_KeyCode::SDLK_l as _);
+    pub const M: Keycode = Keycode(SDL_KeyCode::SDLK_m as _);
+    pub const N: Keycode = Keycode(SDL_KeyCode::SDLK_n as _);
+    pub const O: Keycode = Keycode(SDL_KeyCode::SDLK_o as _);
+    pub const P: Keycode = Keycode(SDL_KeyCode::SDLK_p as _);
+    pub const Q: Keycode = Keycode(SDL_KeyCode::SDLK_q as _);
+    pub const R: Keycode = Keycode(SDL_KeyCode::SDLK_r as _);
+    pub const S: Keycode = Keycode(SDL_KeyCode::SDLK_s as _);
+    pub const T: Keycode = Keycode(SDL_KeyCode::SDLK_t as _);
+    pub const U: Keycode = Keycode(SDL_KeyCode::SDLK_u as _);
+    pub const V: Keycode = Keycode(SDL_KeyCode::SDLK_v as _);
+    pub const W: Keycode = Keycode(SDL_KeyCode::SDLK_w as _);
+    pub const X: Keycode = Keycode(SDL_KeyCode::SDLK_x as _);
+    pub const Y: Keycode = Keycode(SDL_KeyCode::SDLK_y as _);
+    pub const Z: Keycode = Keycode(SDL_KeyCode::SDLK_z as _);
+    pub const CAPSLOCK: Keycode = Keycode(SDL_KeyCode::SDLK_CAPSLOCK as _);
+    pub const F1: Keycode = Keycode(SDL_KeyCode::SDLK_F1 as _);
+    pub const F2: Keycode = Keycode(SDL_KeyCode::SDLK_F2 as _);
+    pub const F3: Keycode = Keycode(SDL_KeyCode::SDLK_F3 as _);
+    pub const F4: Keycode = Keycode(SDL_KeyCode::SDLK_F4 as _);
+    pub const F5: Keycode = Keycode(SDL_KeyCode::SDLK_F5 as _);
+    pub const F6: Keycode = Keycode(SDL_KeyCode::SDLK_F6 as _);
+    pub const F7: Keycode = Keycode(SDL_KeyCode::SDLK_F7 as _);
+    pub const F8: Keycode = Keycode(SDL_KeyCode::SDLK_F8 as _);
+    pub const F9: Keycode = Keycode(SDL_KeyCode::SDLK_F9 as _);
+    pub const F10: Keycode = Keycode(SDL_KeyCode::SDLK_F10 as _);
+    pub const F11: Keycode = Keycode(SDL_KeyCode::SDLK_F11 as _);
+    pub const F12: Keycode = Keycode(SDL_KeyCode::SDLK_F12 as _);
+    pub const PRINTSCREEN: Keycode = Keycode(SDL_KeyCode::SDLK_PRINTSCREEN as _);
+    pub const SCROLLLOCK: Keycode = Keycode(SDL_KeyCode::SDLK_SCROLLLOCK as _);
+    pub const PAUSE: Keycode = Keycode(SDL_KeyCode::SDLK_PAUSE as _);
+    pub const INSERT: Keycode = Keycode(SDL_KeyCode::SDLK_INSERT as _);
+    pub const HOME: Keycode = Keycode(SDL_KeyCode::SDLK_HOME as _);
+    pub const PAGEUP: Keycode = Keycode(SDL_KeyCode::SDLK_PAGEUP as _);
+    pub const DELETE: Keycode = Keycode(SDL_KeyCode::SDLK_DELETE as _);
+    pub const END: Keycode = Keycode(SDL_KeyCode::SDLK_END as _);
+    pub const PAGEDOWN: Keycode = Keycode(SDL_KeyCode::SDLK_PAGEDOWN as _);
+    pub const RIGHT: Keycode = Keycode(SDL_KeyCode::SDLK_RIGHT as _);
+    pub const LEFT: Keycode = Keycode(SDL_KeyCode::SDLK_LEFT as _);
+    pub const DOWN: Keycode = Keycode(SDL_KeyCode::SDLK_DOWN as _);
+    pub const UP: Keycode = Keycode(SDL_KeyCode::SDLK_UP as _);
+    pub const NUMLOCKCLEAR: Keycode = Keycode(SDL_KeyCode::SDLK_NUMLOCKCLEAR as _);
+    pub const KP_DIVIDE: Keycode = Keycode(SDL_KeyCode::SDLK_KP_DIVIDE as _);
+    pub const KP_MULTIPLY: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MULTIPLY as _);
+    pub const KP_MINUS: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MINUS as _);
+    pub const KP_PLUS: Keycode = Keycode(SDL_KeyCode::SDLK_KP_PLUS as _);
+    pub const KP_ENTER: Keycode = Keycode(SDL_KeyCode::SDLK_KP_ENTER as _);
+    pub const KP_1: Keycode = Keycode(SDL_KeyCode::SDLK_KP_1 as _);
+    pub const KP_2: Keycode = Keycode(SDL_KeyCode::SDLK_KP_2 as _);
+    pub const KP_3: Keycode = Keycode(SDL_KeyCode::SDLK_KP_3 as _);
+    pub const KP_4: Keycode = Keycode(SDL_KeyCode::SDLK_KP_4 as _);
+    pub const KP_5: Keycode = Keycode(SDL_KeyCode::SDLK_KP_5 as _);
+    pub const KP_6: Keycode = Keycode(SDL_KeyCode::SDLK_KP_6 as _);
+    pub const KP_7: Keycode = Keycode(SDL_KeyCode::SDLK_KP_7 as _);
+    pub const KP_8: Keycode = Keycode(SDL_KeyCode::SDLK_KP_8 as _);
+    pub const KP_9: Keycode = Keycode(SDL_KeyCode::SDLK_KP_9 as _);
+    pub const KP_0: Keycode = Keycode(SDL_KeyCode::SDLK_KP_0 as _);
+    pub const KP_PERIOD: Keycode = Keycode(SDL_KeyCode::SDLK_KP_PERIOD as _);
+    pub const APPLICATION: Keycode = Keycode(SDL_KeyCode::SDLK_APPLICATION as _);
+    pub const POWER: Keycode = Keycode(SDL_KeyCode::SDLK_POWER as _);
+    pub const KP_EQUALS: Keycode = Keycode(SDL_KeyCode::SDLK_KP_EQUALS as _);
+    pub const F13: Keycode = Keycode(SDL_KeyCode::SDLK_F13 as _);
+    pub const F14: Keycode = Keycode(SDL_KeyCode::SDLK_F14 as _);
+    pub const F15: Keycode = Keycode(SDL_KeyCode::SDLK_F15 as _);
+    pub const F16: Keycode = Keycode(SDL_KeyCode::SDLK_F16 as _);
+    pub const F17: Keycode = Keycode(SDL_KeyCode::SDLK_F17 as _);
+    pub const F18: Keycode = Keycode(SDL_KeyCode::SDLK_F18 as _);
+    pub const F19: Keycode = Keycode(SDL_KeyCode::SDLK_F19 as _);
+    pub const F20: Keycode = Keycode(SDL_KeyCode::SDLK_F20 as _);
+    pub const F21: Keycode = Keycode(SDL_KeyCode::SDLK_F21 as _);
+    pub const F22: Keycode = Keycode(SDL_KeyCode::SDLK_F22 as _);
+    pub const F23: Keycode = Keycode(SDL_KeyCode::SDLK_F23 as _);
+    pub const F24: Keycode = Keycode(SDL_KeyCode::SDLK_F24 as _);
+    pub const EXECUTE: Keycode = Keycode(SDL_KeyCode::SDLK_EXECUTE as _);
+    pub const HELP: Keycode = Keycode(SDL_KeyCode::SDLK_HELP as _);
+    pub const MENU: Keycode = Keycode(SDL_KeyCode::SDLK_MENU as _);
+    pub const SELECT: Keycode = Keycode(SDL_KeyCode::SDLK_SELECT as _);
+    pub const STOP: Keycode = Keycode(SDL_KeyCode::SDLK_STOP as _);
+    pub const AGAIN: Keycode = Keycode(SDL_KeyCode::SDLK_AGAIN as _);
+    pub const UNDO: Keycode = Keycode(SDL_KeyCode::SDLK_UNDO as _);
+    pub const CUT: Keycode = Keycode(SDL_KeyCode::SDLK_CUT as _);
+    pub const COPY: Keycode = Keycode(SDL_KeyCode::SDLK_COPY as _);
+    pub const PASTE: Keycode = Keycode(SDL_KeyCode::SDLK_PASTE as _);
+    pub const FIND: Keycode = Keycode(SDL_KeyCode::SDLK_FIND as _);
+    pub const MUTE: Keycode = Keycode(SDL_KeyCode::SDLK_MUTE as _);
+    pub const VOLUMEUP: Keycode = Keycode(SDL_KeyCode::SDLK_VOLUMEUP as _);
+    pub const VOLUMEDOWN: Keycode = Keycode(SDL_KeyCode::SDLK_VOLUMEDOWN as _);
+    pub const KP_COMMA: Keycode = Keycode(SDL_KeyCode::SDLK_KP_COMMA as _);
+    pub const KP_EQUALSAS400: Keycode = Keycode(SDL_KeyCode::SDLK_KP_EQUALSAS400 as _);
+    pub const ALTERASE: Keycode = Keycode(SDL_KeyCode::SDLK_ALTERASE as _);
+    pub const SYSREQ: Keycode = Keycode(SDL_KeyCode::SDLK_SYSREQ as _);
+    pub const CANCEL: Keycode = Keycode(SDL_KeyCode::SDLK_CANCEL as _);
+    pub const CLEAR: Keycode = Keycode(SDL_KeyCode::SDLK_CLEAR as _);
+    pub const PRIOR: Keycode = Keycode(SDL_KeyCode::SDLK_PRIOR as _);
+    pub const RETURN2: Keycode = Keycode(SDL_KeyCode::SDLK_RETURN2 as _);
+    pub const SEPARATOR: Keycode = Keycode(SDL_KeyCode::SDLK_SEPARATOR as _);
+    pub const OUT: Keycode = Keycode(SDL_KeyCode::SDLK_OUT as _);
+    pub const OPER: Keycode = Keycode(SDL_KeyCode::SDLK_OPER as _);
+    pub const CLEARAGAIN: Keycode = Keycode(SDL_KeyCode::SDLK_CLEARAGAIN as _);
+    pub const CRSEL: Keycode = Keycode(SDL_KeyCode::SDLK_CRSEL as _);
+    pub const EXSEL: Keycode = Keycode(SDL_KeyCode::SDLK_EXSEL as _);
+    pub const KP_00: Keycode = Keycode(SDL_KeyCode::SDLK_KP_00 as _);
+    pub const KP_000: Keycode = Keycode(SDL_KeyCode::SDLK_KP_000 as _);
+    pub const THOUSANDSSEPARATOR: Keycode = Keycode(SDL_KeyCode::SDLK_THOUSANDSSEPARATOR as _);
+    pub const DECIMALSEPARATOR: Keycode = Keycode(SDL_KeyCode::SDLK_DECIMALSEPARATOR as _);
+    pub const CURRENCYUNIT: Keycode = Keycode(SDL_KeyCode::SDLK_CURRENCYUNIT as _);
+    pub const CURRENCYSUBUNIT: Keycode = Keycode(SDL_KeyCode::SDLK_CURRENCYSUBUNIT as _);
+    pub const KP_LEFTPAREN: Keycode = Keycode(SDL_KeyCode::SDLK_KP_LEFTPAREN as _);
+    pub const KP_RIGHTPAREN: Keycode = Keycode(SDL_KeyCode::SDLK_KP_RIGHTPAREN as _);
+    pub const KP_LEFTBRACE: Keycode = Keycode(SDL_KeyCode::SDLK_KP_LEFTBRACE as _);
+    pub const KP_RIGHTBRACE: Keycode = Keycode(SDL_KeyCode::SDLK_KP_RIGHTBRACE as _);
+    pub const KP_TAB: Keycode = Keycode(SDL_KeyCode::SDLK_KP_TAB as _);
+    pub const KP_BACKSPACE: Keycode = Keycode(SDL_KeyCode::SDLK_KP_BACKSPACE as _);
+    pub const KP_A: Keycode = Keycode(SDL_KeyCode::SDLK_KP_A as _);
+    pub const KP_B: Keycode = Keycode(SDL_KeyCode::SDLK_KP_B as _);
+    pub const KP_C: Keycode = Keycode(SDL_KeyCode::SDLK_KP_C as _);
+    pub const KP_D: Keycode = Keycode(SDL_KeyCode::SDLK_KP_D as _);
+    pub const KP_E: Keycode = Keycode(SDL_KeyCode::SDLK_KP_E as _);
+    pub const KP_F: Keycode = Keycode(SDL_KeyCode::SDLK_KP_F as _);
+    pub const KP_XOR: Keycode = Keycode(SDL_KeyCode::SDLK_KP_XOR as _);
+    pub const KP_POWER: Keycode = Keycode(SDL_KeyCode::SDLK_KP_POWER as _);
+    pub const KP_PERCENT: Keycode = Keycode(SDL_KeyCode::SDLK_KP_PERCENT as _);
+    pub const KP_LESS: Keycode = Keycode(SDL_KeyCode::SDLK_KP_LESS as _);
+    pub const KP_GREATER: Keycode = Keycode(SDL_KeyCode::SDLK_KP_GREATER as _);
+    pub const KP_AMPERSAND: Keycode = Keycode(SDL_KeyCode::SDLK_KP_AMPERSAND as _);
+    pub const KP_DBLAMPERSAND: Keycode = Keycode(SDL_KeyCode::SDLK_KP_DBLAMPERSAND as _);
+    pub const KP_VERTICALBAR: Keycode = Keycode(SDL_KeyCode::SDLK_KP_VERTICALBAR as _);
+    pub const KP_DBLVERTICALBAR: Keycode = Keycode(SDL_KeyCode::SDLK_KP_DBLVERTICALBAR as _);
+    pub const KP_COLON: Keycode = Keycode(SDL_KeyCode::SDLK_KP_COLON as _);
+    pub const KP_HASH: Keycode = Keycode(SDL_KeyCode::SDLK_KP_HASH as _);
+    pub const KP_SPACE: Keycode = Keycode(SDL_KeyCode::SDLK_KP_SPACE as _);
+    pub const KP_AT: Keycode = Keycode(SDL_KeyCode::SDLK_KP_AT as _);
+    pub const KP_EXCLAM: Keycode = Keycode(SDL_KeyCode::SDLK_KP_EXCLAM as _);
+    pub const KP_MEMSTORE: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MEMSTORE as _);
+    pub const KP_MEMRECALL: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MEMRECALL as _);
+    pub const KP_MEMCLEAR: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MEMCLEAR as _);
+    pub const KP_MEMADD: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MEMADD as _);
+    pub const KP_MEMSUBTRACT: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MEMSUBTRACT as _);
+    pub const KP_MEMMULTIPLY: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MEMMULTIPLY as _);
+    pub const KP_MEMDIVIDE: Keycode = Keycode(SDL_KeyCode::SDLK_KP_MEMDIVIDE as _);
+    pub const KP_PLUSMINUS: Keycode = Keycode(SDL_KeyCode::SDLK_KP_PLUSMINUS as _);
+    pub const KP_CLEAR: Keycode = Keycode(SDL_KeyCode::SDLK_KP_CLEAR as _);
+    pub const KP_CLEARENTRY: Keycode = Keycode(SDL_KeyCode::SDLK_KP_CLEARENTRY as _);
+    pub const KP_BINARY: Keycode = Keycode(SDL_KeyCode::SDLK_KP_BINARY as _);
+    pub const KP_OCTAL: Keycode = Keycode(SDL_KeyCode::SDLK_KP_OCTAL as _);
+    pub const KP_DECIMAL: Keycode = Keycode(SDL_KeyCode::SDLK_KP_DECIMAL as _);
+    pub const KP_HEXADECIMAL: Keycode = Keycode(SDL_KeyCode::SDLK_KP_HEXADECIMAL as _);
+    pub const LCTRL: Keycode = Keycode(SDL_KeyCode::SDLK_LCTRL as _);
+    pub const LSHIFT: Keycode = Keycode(SDL_KeyCode::SDLK_LSHIFT as _);
+    pub const LALT: Keycode = Keycode(SDL_KeyCode::SDLK_LALT as _);
+    pub const LGUI: Keycode = Keycode(SDL_KeyCode::SDLK_LGUI as _);
+    pub const RCTRL: Keycode = Keycode(SDL_KeyCode::SDLK_RCTRL as _);
+    pub const RSHIFT: Keycode = Keycode(SDL_KeyCode::SDLK_RSHIFT as _);
+    pub const RALT: Keycode = Keycode(SDL_KeyCode::SDLK_RALT as _);
+    pub const RGUI: Keycode = Keycode(SDL_KeyCode::SDLK_RGUI as _);
+    pub const MODE: Keycode = Keycode(SDL_KeyCode::SDLK_MODE as _);
+    pub const AUDIONEXT: Keycode = Keycode(SDL_KeyCode::SDLK_AUDIONEXT as _);
+    pub const AUDIOPREV: Keycode = Keycode(SDL_KeyCode::SDLK_AUDIOPREV as _);
+    pub const AUDIOSTOP: Keycode = Keycode(SDL_KeyCode::SDLK_AUDIOSTOP as _);
+    pub const AUDIOPLAY: Keycode = Keycode(SDL_KeyCode::SDLK_AUDIOPLAY as _);
+    pub const AUDIOMUTE: Keycode = Keycode(SDL_KeyCode::SDLK_AUDIOMUTE as _);
+    pub const MEDIASELECT: Keycode = Keycode(SDL_KeyCode::SDLK_MEDIASELECT as _);
+    pub const WWW: Keycode = Keycode(SDL_KeyCode::SDLK_WWW as _);
+    pub const MAIL: Keycode = Keycode(SDL_KeyCode::SDLK_MAIL as _);
+    pub const CALCULATOR: Keycode = Keycode(SDL_KeyCode::SDLK_CALCULATOR as _);
+    pub const COMPUTER: Keycode = Keycode(SDL_KeyCode::SDLK_COMPUTER as _);
+    pub const AC_SEARCH: Keycode = Keycode(SDL_KeyCode::SDLK_AC_SEARCH as _);
+    pub const AC_HOME: Keycode = Keycode(SDL_KeyCode::SDLK_AC_HOME as _);
+    pub const AC_BACK: Keycode = Keycode(SDL_KeyCode::SDLK_AC_BACK as _);
+    pub const AC_FORWARD: Keycode = Keycode(SDL_KeyCode::SDLK_AC_FORWARD as _);
+    pub const AC_STOP: Keycode = Keycode(SDL_KeyCode::SDLK_AC_STOP as _);
+    pub const AC_REFRESH: Keycode = Keycode(SDL_KeyCode::SDLK_AC_REFRESH as _);
+    pub const AC_BOOKMARKS: Keycode = Keycode(SDL_KeyCode::SDLK_AC_BOOKMARKS as _);
+    pub const BRIGHTNESSDOWN: Keycode = Keycode(SDL_KeyCode::SDLK_BRIGHTNESSDOWN as _);
+    pub const BRIGHTNESSUP: Keycode = Keycode(SDL_KeyCode::SDLK_BRIGHTNESSUP as _);
+    pub const DISPLAYSWITCH: Keycode = Keycode(SDL_KeyCode::SDLK_DISPLAYSWITCH as _);
+    pub const KBDILLUMTOGGLE: Keycode = Keycode(SDL_KeyCode::SDLK_KBDILLUMTOGGLE as _);
+    pub const KBDILLUMDOWN: Keycode = Keycode(SDL_KeyCode::SDLK_KBDILLUMDOWN as _);
+    pub const KBDILLUMUP: Keycode = Keycode(SDL_KeyCode::SDLK_KBDILLUMUP as _);
+    pub const EJECT: Keycode = Keycode(SDL_KeyCode::SDLK_EJECT as _);
+    pub const SLEEP: Keycode = Keycode(SDL_KeyCode::SDLK_SLEEP as _);
+    pub const APP1: Keycode = Keycode(SDL_KeyCode::SDLK_APP1 as _);
+    pub const APP2: Keycode = Keycode(SDL_KeyCode::SDLK_APP2 as _);
+    pub const AUDIOREWIND: Keycode = Keycode(SDL_KeyCode::SDLK_AUDIOREWIND as _);
+    pub const AUDIOFASTFORWARD: Keycode = Keycode(SDL_KeyCode::SDLK_AUDIOFASTFORWARD as _);
+
+    /// Get a human-readable name for this key.
+    /// 
+    /// Returns an empty string if this key does not have a name.
+    #[doc(alias = "SDL_GetKeyName")]
+    pub fn name(&self) -> String {
+        // `SDL_GetKeyName` formats character keys into a shared buffer, which is not
+        // thread-safe, so only the static scancode names are taken from SDL.
+        if self.0 as u32 & SDLK_SCANCODE_MASK != 0 {
+            return Scancode(self.0 as u32 & !SDLK_SCANCODE_MASK).name();
+        }
+
+        match *self {
+            Keycode::RETURN => Scancode::RETURN.name(),
+            Keycode::ESCAPE => Scancode::ESCAPE.name(),
+            Keycode::BACKSPACE => Scancode::BACKSPACE.name(),
+            Keycode::TAB => Scancode::TAB.name(),
+            Keycode::SPACE => Scancode::SPACE.name(),
+            Keycode::DELETE => Scancode::DELETE.name(),
+
+            // Letter keys are labeled in upper case, matching SDL.
+            key => match char::from_u32(key.0 as _) {
+                Option::Some('\0') | Option::None => String::new(),
+                Option::Some(c) => c.to_ascii_uppercase().to_string()
+            }
+        }
+    }
+
+    /// Get the key with the given human-readable name, as returned by [`Keycode::name`].
+    /// 
+    /// Returns [`Option::None`] if the name is not recognized.
+    #[doc(alias = "SDL_GetKeyFromName")]
+    pub fn from_name(name: &str) -> Option<Keycode> {
+        let name = CString::new(name).ok()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetKeyFromName'");
+        match unsafe { SDL_GetKeyFromName(name.as_ptr()) } {
+            0 => Option::None,
+            key => Option::Some(Keycode(key))
+        }
+    }
+
+    /// Get the key mapped to the given physical key in the current keyboard layout.
+    /// 
+    /// The keyboard layout is only known once the Video subsystem is initialized.
+    #[doc(alias = "SDL_GetKeyFromScancode")]
+    pub fn from_scancode(_events: &SdlSubsystem<Events>, scancode: Scancode) -> Keycode {
+        // `SDL_Scancode` is a Rust enum in the bindings, so the function is
+        // called with the raw value to avoid constructing an invalid variant.
+        let get_key_from_scancode: unsafe extern "C" fn(u32) -> SDL_Keycode = unsafe {
+            mem::transmute(SDL_GetKeyFromScancode as unsafe extern "C" fn(SDL_Scancode) -> SDL_Keycode)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetKeyFromScancode'");
+        Keycode(unsafe { get_key_from_scancode(scancode.0) })
+    }
+}
+
+impl fmt::Display for Keycode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
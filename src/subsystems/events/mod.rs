@@ -0,0 +1,235 @@
+use std::{marker::PhantomData, mem::MaybeUninit};
+use crate::{sys::*, SdlError, utils::get_sys_error};
+use super::{SdlSubsystem, SdlSubsystemFlag, markers::Events};
+
+pub mod event;
+pub mod custom;
+pub mod sender;
+pub mod filter;
+pub mod queue;
+pub mod keycode;
+pub mod scancode;
+pub mod keyboard;
+use event::Event;
+use custom::CustomEventData;
+use sender::EventSender;
+
+/// A handle used to read events from the SDL event queue.
+/// 
+/// Obtained via [`SdlSubsystem<Events>::event_pump`].
+pub struct EventPump<'a> {
+    pub(crate) events: PhantomData<&'a SdlSubsystem<Events>>
+}
+
+impl SdlSubsystem<Events> {
+
+    /// Get an [`EventPump`] used to read events from the event queue.
+    pub fn event_pump(&self) -> EventPump<'_> {
+        EventPump { events: PhantomData }
+    }
+
+    /// Get an [`EventSender`], which can push events from any thread.
+    pub fn event_sender(&self) -> EventSender {
+        EventSender { _private: PhantomData }
+    }
+
+    /// Reserve `count` consecutive event types for user events, returning the first.
+    /// 
+    /// Events of these types can be pushed with [`Event::User`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RegisterEvents")]
+    pub fn register_events(&self, count: u32) -> Result<u32, SdlError> {
+        custom::register_events(count)
+    }
+
+    /// Get the event type used for custom events carrying a `T`.
+    /// 
+    /// An event type is registered the first time this is called for each `T`,
+    /// either directly or via [`SdlSubsystem<Events>::push_custom_event`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    pub fn register_custom_event<T: 'static + Send>(&self) -> Result<u32, SdlError> {
+        custom::register_custom_event::<T>()
+    }
+
+    /// Add an event to the event queue.
+    /// 
+    /// Returns `false` if the event was filtered out, in which case any data
+    /// owned by the event is dropped.
+    /// 
+    /// Events which SDL would read pointers from, but which do not carry valid ones,
+    /// are rejected:
+    /// 
+    /// ```
+    /// # use sdl2_rs::{SdlContext, SdlError, subsystems::events::event::{Event, EventType}};
+    /// # use std::ptr;
+    /// let context = SdlContext::new()?;
+    /// let events = context.events()?;
+    /// 
+    /// // User events must use a user event type.
+    /// let user = Event::User {
+    ///     timestamp: 0,
+    ///     window_id: 0,
+    ///     type_: EventType::DropFile as u32,
+    ///     code: 0,
+    ///     data1: ptr::null_mut(),
+    ///     data2: ptr::null_mut()
+    /// };
+    /// assert!(matches!(events.push_event(user), Err(SdlError::InvalidValue(_))));
+    /// 
+    /// // Drop events cannot be pushed without their file name.
+    /// let unknown = Event::Unknown { timestamp: 0, type_: EventType::DropFile as u32 };
+    /// assert!(matches!(events.push_event(unknown), Err(SdlError::InvalidValue(_))));
+    /// # Ok::<(), SdlError>(())
+    /// ```
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `event` is an [`Event::User`] using a type below
+    ///   [`EventType::User`](event::EventType::User) or one registered for custom events, an [`Event::Custom`]
+    ///   using an unregistered type, or an [`Event::Unknown`] using a type whose event holds a pointer.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_PushEvent")]
+    pub fn push_event(&self, event: Event) -> Result<bool, SdlError> {
+        push_event(event)
+    }
+
+    /// Add a custom event carrying `value` to the event queue.
+    /// 
+    /// The value can be read back with [`Event::as_user_event`] or
+    /// [`Event::into_user_event`], and is dropped along with the event.
+    /// 
+    /// Returns `false` if the event was filtered out, in which case `value` is dropped.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    pub fn push_custom_event<T: 'static + Send>(&self, value: T) -> Result<bool, SdlError> {
+        push_custom_event(value)
+    }
+}
+
+/// Remove all events with types between `min_type` and `max_type` (inclusive)
+/// from the event queue, dropping any data they own.
+pub(crate) fn discard_events(min_type: u32, max_type: u32) {
+    let mut raw = MaybeUninit::uninit();
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_PeepEvents'");
+    while unsafe { SDL_PeepEvents(raw.as_mut_ptr(), 1, SDL_eventaction::SDL_GETEVENT, min_type, max_type) } == 1 {
+        drop(unsafe { Event::from_ll(raw.assume_init()) });
+    }
+}
+
+/// Push an event, reclaiming any owned data if SDL does not queue it.
+pub(crate) fn push_event(event: Event) -> Result<bool, SdlError> {
+    event.check_pushable()?;
+
+    let mut raw = event.into_ll();
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_PushEvent'");
+    match unsafe { SDL_PushEvent(&mut raw) } {
+        1 => Ok(true),
+
+        // The event filter rejected the event, and has already dropped its data.
+        0 => Ok(false),
+
+        _ => {
+            let error = get_sys_error().unwrap();
+
+            // Nothing else holds the event, so it is converted back to drop its data.
+            drop(unsafe { Event::from_ll(raw) });
+
+            Err(SdlError::SysError(error))
+        }
+    }
+}
+
+/// Push a custom event carrying `value`.
+pub(crate) fn push_custom_event<T: 'static + Send>(value: T) -> Result<bool, SdlError> {
+    let type_ = custom::register_custom_event::<T>()?;
+
+    push_event(Event::Custom {
+        timestamp: 0,
+        type_,
+        data: CustomEventData::new(value)
+    })
+}
+
+impl<'a> EventPump<'a> {
+
+    /// Poll for currently pending events.
+    /// 
+    /// Returns [`Option::None`] if there are no pending events.
+    #[doc(alias = "SDL_PollEvent")]
+    pub fn poll_event(&mut self) -> Option<Event> {
+        let mut raw = MaybeUninit::uninit();
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_PollEvent'");
+        if unsafe { SDL_PollEvent(raw.as_mut_ptr()) } == 1 {
+            Option::Some(unsafe { Event::from_ll(raw.assume_init()) })
+        } else {
+            Option::None
+        }
+    }
+
+    /// Wait indefinitely for the next available event.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_WaitEvent")]
+    pub fn wait_event(&mut self) -> Result<Event, SdlError> {
+        let mut raw = MaybeUninit::uninit();
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_WaitEvent'");
+        if unsafe { SDL_WaitEvent(raw.as_mut_ptr()) } == 1 {
+            Ok(unsafe { Event::from_ll(raw.assume_init()) })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Wait until the specified timeout (in milliseconds) for the next available event.
+    /// 
+    /// Returns [`Option::None`] if the timeout elapsed.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NotInitialized`]
+    #[doc(alias = "SDL_WaitEventTimeout")]
+    pub fn wait_event_timeout(&mut self, timeout: u32) -> Result<Option<Event>, SdlError> {
+        // SDL returns 0 both on timeout and on error, and only fails to
+        // wait if the events subsystem is not initialized.
+        if !SdlSubsystemFlag::Events.initialized() {
+            return Err(SdlError::NotInitialized(String::from("The Events subsystem is not initialized.")));
+        }
+
+        let mut raw = MaybeUninit::uninit();
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_WaitEventTimeout'");
+        if unsafe { SDL_WaitEventTimeout(raw.as_mut_ptr(), timeout as _) } == 1 {
+            Ok(Option::Some(unsafe { Event::from_ll(raw.assume_init()) }))
+        } else {
+            Ok(Option::None)
+        }
+    }
+
+    /// Returns an iterator which polls for events until the queue is empty.
+    /// 
+    /// See [`EventPump::poll_event`].
+    pub fn poll_iter(&mut self) -> EventPollIterator<'_, 'a> {
+        EventPollIterator { pump: self }
+    }
+}
+
+/// An iterator which polls for pending events, see [`EventPump::poll_iter`].
+pub struct EventPollIterator<'p, 'a> {
+    pump: &'p mut EventPump<'a>
+}
+
+impl Iterator for EventPollIterator<'_, '_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        self.pump.poll_event()
+    }
+}
@@ -0,0 +1,168 @@
+use std::mem::MaybeUninit;
+use crate::{sys::*, SdlError, utils::get_sys_error, subsystems::{SdlSubsystem, markers::Events}};
+use super::{EventPump, discard_events, event::{Event, EventType}};
+
+/// Actions used to read from the event queue, see [`SdlSubsystem<Events>::peep_events`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventAction {
+    /// Return events, leaving them in the event queue.
+    Peek,
+
+    /// Return events, removing them from the event queue.
+    Get
+}
+
+/// Whether events of a given type are processed, see [`SdlSubsystem<Events>::set_event_state`].
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventState {
+    /// Events of this type are dropped from the event queue.
+    Ignore = SDL_IGNORE as _,
+
+    /// Events of this type are processed normally.
+    Enable = SDL_ENABLE as _
+}
+
+impl SdlSubsystem<Events> {
+
+    /// Add events to the back of the event queue, bypassing any event filter and watchers.
+    /// 
+    /// Returns the number of events added. Data owned by events which
+    /// could not be added is dropped.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if any event is rejected by
+    ///   [`SdlSubsystem<Events>::push_event`]. No events are added in this case.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_PeepEvents(SDL_ADDEVENT)")]
+    pub fn add_events(&self, events: Vec<Event>) -> Result<usize, SdlError> {
+        events.iter().try_for_each(Event::check_pushable)?;
+
+        let mut raw: Vec<SDL_Event> = events.into_iter().map(Event::into_ll).collect();
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_PeepEvents(SDL_ADDEVENT)'");
+        let result = unsafe {
+            SDL_PeepEvents(raw.as_mut_ptr(), raw.len() as _, SDL_eventaction::SDL_ADDEVENT, 0, 0)
+        };
+
+        let error = if result < 0 { get_sys_error() } else { Option::None };
+        let added = result.max(0) as usize;
+
+        // Events that were not added are still owned here.
+        for event in raw.drain(added..) {
+            drop(unsafe { Event::from_ll(event) });
+        }
+
+        match error {
+            Option::Some(error) => Err(SdlError::SysError(error)),
+            Option::None => Ok(added)
+        }
+    }
+
+    /// Read up to `max_events` events with types between `min_type` and
+    /// `max_type` (inclusive) from the front of the event queue.
+    /// 
+    /// This does not pump the event loop, see [`EventPump::pump_events`].
+    /// 
+    /// Note: Events returned by [`EventAction::Peek`] remain in the queue, so
+    /// the payload of custom events cannot be read from them.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn peep_events(&self, action: EventAction, max_events: usize, min_type: EventType, max_type: EventType) -> Result<Vec<Event>, SdlError> {
+        let mut raw: Vec<SDL_Event> = Vec::with_capacity(max_events);
+        let sys_action = match action {
+            EventAction::Peek => SDL_eventaction::SDL_PEEKEVENT,
+            EventAction::Get => SDL_eventaction::SDL_GETEVENT
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_PeepEvents'");
+        let result = unsafe {
+            SDL_PeepEvents(raw.as_mut_ptr(), max_events as _, sys_action, min_type as _, max_type as _)
+        };
+
+        if result < 0 {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
+        // SDL initialized the first `result` events.
+        unsafe { raw.set_len(result as _) };
+
+        Ok(raw.iter().map(|event| unsafe {
+            match action {
+                EventAction::Peek => Event::from_ll_detached(event),
+                EventAction::Get => Event::from_ll(*event)
+            }
+        }).collect())
+    }
+
+    /// Returns true if the event queue contains an event of the given type.
+    #[doc(alias = "SDL_HasEvent")]
+    pub fn has_event(&self, event_type: EventType) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HasEvent'");
+        unsafe { SDL_HasEvent(event_type as _) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Returns true if the event queue contains an event with a type
+    /// between `min_type` and `max_type` (inclusive).
+    #[doc(alias = "SDL_HasEvents")]
+    pub fn has_events(&self, min_type: EventType, max_type: EventType) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HasEvents'");
+        unsafe { SDL_HasEvents(min_type as _, max_type as _) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Remove all events of the given type from the event queue, dropping any data they own.
+    #[doc(alias = "SDL_FlushEvent")]
+    pub fn flush_event(&self, event_type: EventType) {
+        discard_events(event_type as _, event_type as _)
+    }
+
+    /// Remove all events with types between `min_type` and `max_type` (inclusive)
+    /// from the event queue, dropping any data they own.
+    /// 
+    /// Unlike `SDL_FlushEvents`, this frees the data owned by drop events and custom events.
+    #[doc(alias = "SDL_FlushEvents")]
+    pub fn flush_events(&self, min_type: EventType, max_type: EventType) {
+        discard_events(min_type as _, max_type as _)
+    }
+
+    /// Get whether events of the given type are processed.
+    #[doc(alias = "SDL_EventState")]
+    pub fn event_state(&self, event_type: EventType) -> EventState {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_EventState(SDL_QUERY)'");
+        match unsafe { SDL_EventState(event_type as _, SDL_QUERY) } as u32 {
+            SDL_DISABLE => EventState::Ignore,
+            _ => EventState::Enable
+        }
+    }
+
+    /// Set whether events of the given type are processed, returning the previous state.
+    /// 
+    /// Ignoring an event type removes any pending events of that type from the queue.
+    #[doc(alias = "SDL_EventState")]
+    pub fn set_event_state(&self, event_type: EventType, state: EventState) -> EventState {
+        if state == EventState::Ignore {
+            // SDL flushes pending events of this type, so they are discarded here first.
+            discard_events(event_type as _, event_type as _);
+        }
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_EventState'");
+        match unsafe { SDL_EventState(event_type as _, state as _) } as u32 {
+            SDL_DISABLE => EventState::Ignore,
+            _ => EventState::Enable
+        }
+    }
+}
+
+impl EventPump<'_> {
+
+    /// Gather events from input devices into the event queue.
+    /// 
+    /// This is done implicitly by [`EventPump::poll_event`] and [`EventPump::wait_event`].
+    #[doc(alias = "SDL_PumpEvents")]
+    pub fn pump_events(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_PumpEvents'");
+        unsafe { SDL_PumpEvents() }
+    }
+}
@@ -0,0 +1,304 @@
+use std::{ffi::{CStr, CString}, fmt, mem};
+use crate::{sys::*, subsystems::{SdlSubsystem, markers::Events}};
+use super::keycode::Keycode;
+
+/// A physical key, independent of the current keyboard layout.
+/// 
+/// See [`Keycode`] for virtual keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Scancode(pub u32);
+
+impl Scancode {
+    pub const UNKNOWN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_UNKNOWN as _);
+    pub const A: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_A as _);
+    pub const B: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_B as _);
+    pub const C: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_C as _);
+    pub const D: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_D as _);
+    pub const E: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_E as _);
+    pub const F: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F as _);
+    pub const G: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_G as _);
+    pub const H: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_H as _);
+    pub const I: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_I as _);
+    pub const J: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_J as _);
+    pub const K: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_K as _);
+    pub const L: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_L as _);
+    pub const M: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_M as _);
+    pub const N: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_N as _);
+    pub const O: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_O as _);
+    pub const P: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_P as _);
+    pub const Q: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_Q as _);
+    pub const R: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_R as _);
+    pub const S: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_S as _);
+    pub const T: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_T as _);
+    pub const U: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_U as _);
+    pub const V: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_V as _);
+    pub const W: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_W as _);
+    pub const X: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_X as _);
+    pub const Y: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_Y as _);
+    pub const Z: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_Z as _);
+    pub const NUM_1: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_1 as _);
+    pub const NUM_2: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_2 as _);
+    pub const NUM_3: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_3 as _);
+    pub const NUM_4: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_4 as _);
+    pub const NUM_5: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_5 as _);
+    pub const NUM_6: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_6 as _);
+    pub const NUM_7: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_7 as _);
+    pub const NUM_8: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_8 as _);
+    pub const NUM_9: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_9 as _);
+    pub const NUM_0: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_0 as _);
+    pub const RETURN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RETURN as _);
+    pub const ESCAPE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_ESCAPE as _);
+    pub const BACKSPACE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_BACKSPACE as _);
+    pub const TAB: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_TAB as _);
+    pub const SPACE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SPACE as _);
+    pub const MINUS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_MINUS as _);
+    pub const EQUALS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_EQUALS as _);
+    pub const LEFTBRACKET: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LEFTBRACKET as _);
+    pub const RIGHTBRACKET: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RIGHTBRACKET as _);
+    pub const BACKSLASH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_BACKSLASH as _);
+    pub const NONUSHASH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_NONUSHASH as _);
+    pub const SEMICOLON: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SEMICOLON as _);
+    pub const APOSTROPHE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_APOSTROPHE as _);
+    pub const GRAVE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_GRAVE as _);
+    pub const COMMA: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_COMMA as _);
+    pub const PERIOD: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_PERIOD as _);
+    pub const SLASH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SLASH as _);
+    pub const CAPSLOCK: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CAPSLOCK as _);
+    pub const F1: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F1 as _);
+    pub const F2: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F2 as _);
+    pub const F3: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F3 as _);
+    pub const F4: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F4 as _);
+    pub const F5: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F5 as _);
+    pub const F6: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F6 as _);
+    pub const F7: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F7 as _);
+    pub const F8: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F8 as _);
+    pub const F9: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F9 as _);
+    pub const F10: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F10 as _);
+    pub const F11: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F11 as _);
+    pub const F12: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F12 as _);
+    pub const PRINTSCREEN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_PRINTSCREEN as _);
+    pub const SCROLLLOCK: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SCROLLLOCK as _);
+    pub const PAUSE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_PAUSE as _);
+    pub const INSERT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INSERT as _);
+    pub const HOME: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_HOME as _);
+    pub const PAGEUP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_PAGEUP as _);
+    pub const DELETE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_DELETE as _);
+    pub const END: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_END as _);
+    pub const PAGEDOWN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_PAGEDOWN as _);
+    pub const RIGHT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RIGHT as _);
+    pub const LEFT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LEFT as _);
+    pub const DOWN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_DOWN as _);
+    pub const UP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_UP as _);
+    pub const NUMLOCKCLEAR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_NUMLOCKCLEAR as _);
+    pub const KP_DIVIDE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_DIVIDE as _);
+    pub const KP_MULTIPLY: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MULTIPLY as _);
+    pub const KP_MINUS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MINUS as _);
+    pub const KP_PLUS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_PLUS as _);
+    pub const KP_ENTER: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_ENTER as _);
+    pub const KP_1: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_1 as _);
+    pub const KP_2: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_2 as _);
+    pub const KP_3: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_3 as _);
+    pub const KP_4: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_4 as _);
+    pub const KP_5: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_5 as _);
+    pub const KP_6: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_6 as _);
+    pub const KP_7: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_7 as _);
+    pub const KP_8: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_8 as _);
+    pub const KP_9: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_9 as _);
+    pub const KP_0: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_0 as _);
+    pub const KP_PERIOD: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_PERIOD as _);
+    pub const NONUSBACKSLASH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_NONUSBACKSLASH as _);
+    pub const APPLICATION: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_APPLICATION as _);
+    pub const POWER: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_POWER as _);
+    pub const KP_EQUALS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_EQUALS as _);
+    pub const F13: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F13 as _);
+    pub const F14: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F14 as _);
+    pub const F15: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F15 as _);
+    pub const F16: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F16 as _);
+    pub const F17: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F17 as _);
+    pub const F18: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F18 as _);
+    pub const F19: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F19 as _);
+    pub const F20: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F20 as _);
+    pub const F21: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F21 as _);
+    pub const F22: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F22 as _);
+    pub const F23: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F23 as _);
+    pub const F24: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_F24 as _);
+    pub const EXECUTE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_EXECUTE as _);
+    pub const HELP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_HELP as _);
+    pub const MENU: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_MENU as _);
+    pub const SELECT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SELECT as _);
+    pub const STOP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_STOP as _);
+    pub const AGAIN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AGAIN as _);
+    pub const UNDO: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_UNDO as _);
+    pub const CUT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CUT as _);
+    pub const COPY: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_COPY as _);
+    pub const PASTE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_PASTE as _);
+    pub const FIND: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_FIND as _);
+    pub const MUTE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_MUTE as _);
+    pub const VOLUMEUP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_VOLUMEUP as _);
+    pub const VOLUMEDOWN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_VOLUMEDOWN as _);
+    pub const KP_COMMA: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_COMMA as _);
+    pub const KP_EQUALSAS400: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_EQUALSAS400 as _);
+    pub const INTERNATIONAL1: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL1 as _);
+    pub const INTERNATIONAL2: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL2 as _);
+    pub const INTERNATIONAL3: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL3 as _);
+    pub const INTERNATIONAL4: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL4 as _);
+    pub const INTERNATIONAL5: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL5 as _);
+    pub const INTERNATIONAL6: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL6 as _);
+    pub const INTERNATIONAL7: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL7 as _);
+    pub const INTERNATIONAL8: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL8 as _);
+    pub const INTERNATIONAL9: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_INTERNATIONAL9 as _);
+    pub const LANG1: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG1 as _);
+    pub const LANG2: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG2 as _);
+    pub const LANG3: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG3 as _);
+    pub const LANG4: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG4 as _);
+    pub const LANG5: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG5 as _);
+    pub const LANG6: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG6 as _);
+    pub const LANG7: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG7 as _);
+    pub const LANG8: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG8 as _);
+    pub const LANG9: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LANG9 as _);
+    pub const ALTERASE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_ALTERASE as _);
+    pub const SYSREQ: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SYSREQ as _);
+    pub const CANCEL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CANCEL as _);
+    pub const CLEAR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CLEAR as _);
+    pub const PRIOR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_PRIOR as _);
+    pub const RETURN2: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RETURN2 as _);
+    pub const SEPARATOR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SEPARATOR as _);
+    pub const OUT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_OUT as _);
+    pub const OPER: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_OPER as _);
+    pub const CLEARAGAIN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CLEARAGAIN as _);
+    pub const CRSEL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CRSEL as _);
+    pub const EXSEL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_EXSEL as _);
+    pub const KP_00: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_00 as _);
+    pub const KP_000: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_000 as _);
+    pub const THOUSANDSSEPARATOR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_THOUSANDSSEPARATOR as _);
+    pub const DECIMALSEPARATOR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_DECIMALSEPARATOR as _);
+    pub const CURRENCYUNIT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CURRENCYUNIT as _);
+    pub const CURRENCYSUBUNIT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CURRENCYSUBUNIT as _);
+    pub const KP_LEFTPAREN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_LEFTPAREN as _);
+    pub const KP_RIGHTPAREN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_RIGHTPAREN as _);
+    pub const KP_LEFTBRACE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_LEFTBRACE as _);
+    pub const KP_RIGHTBRACE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_RIGHTBRACE as _);
+    pub const KP_TAB: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_TAB as _);
+    pub const KP_BACKSPACE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_BACKSPACE as _);
+    pub const KP_A: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_A as _);
+    pub const KP_B: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_B as _);
+    pub const KP_C: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_C as _);
+    pub const KP_D: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_D as _);
+    pub const KP_E: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_E as _);
+    pub const KP_F: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_F as _);
+    pub const KP_XOR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_XOR as _);
+    pub const KP_POWER: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_POWER as _);
+    pub const KP_PERCENT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_PERCENT as _);
+    pub const KP_LESS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_LESS as _);
+    pub const KP_GREATER: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_GREATER as _);
+    pub const KP_AMPERSAND: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_AMPERSAND as _);
+    pub const KP_DBLAMPERSAND: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_DBLAMPERSAND as _);
+    pub const KP_VERTICALBAR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_VERTICALBAR as _);
+    pub const KP_DBLVERTICALBAR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_DBLVERTICALBAR as _);
+    pub const KP_COLON: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_COLON as _);
+    pub const KP_HASH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_HASH as _);
+    pub const KP_SPACE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_SPACE as _);
+    pub const KP_AT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_AT as _);
+    pub const KP_EXCLAM: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_EXCLAM as _);
+    pub const KP_MEMSTORE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MEMSTORE as _);
+    pub const KP_MEMRECALL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MEMRECALL as _);
+    pub const KP_MEMCLEAR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MEMCLEAR as _);
+    pub const KP_MEMADD: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MEMADD as _);
+    pub const KP_MEMSUBTRACT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MEMSUBTRACT as _);
+    pub const KP_MEMMULTIPLY: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MEMMULTIPLY as _);
+    pub const KP_MEMDIVIDE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_MEMDIVIDE as _);
+    pub const KP_PLUSMINUS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_PLUSMINUS as _);
+    pub const KP_CLEAR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_CLEAR as _);
+    pub const KP_CLEARENTRY: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_CLEARENTRY as _);
+    pub const KP_BINARY: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_BINARY as _);
+    pub const KP_OCTAL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_OCTAL as _);
+    pub const KP_DECIMAL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_DECIMAL as _);
+    pub const KP_HEXADECIMAL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KP_HEXADECIMAL as _);
+    pub const LCTRL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LCTRL as _);
+    pub const LSHIFT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LSHIFT as _);
+    pub const LALT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LALT as _);
+    pub const LGUI: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_LGUI as _);
+    pub const RCTRL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RCTRL as _);
+    pub const RSHIFT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RSHIFT as _);
+    pub const RALT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RALT as _);
+    pub const RGUI: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_RGUI as _);
+    pub const MODE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_MODE as _);
+    pub const AUDIONEXT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AUDIONEXT as _);
+    pub const AUDIOPREV: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AUDIOPREV as _);
+    pub const AUDIOSTOP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AUDIOSTOP as _);
+    pub const AUDIOPLAY: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AUDIOPLAY as _);
+    pub const AUDIOMUTE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AUDIOMUTE as _);
+    pub const MEDIASELECT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_MEDIASELECT as _);
+    pub const WWW: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_WWW as _);
+    pub const MAIL: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_MAIL as _);
+    pub const CALCULATOR: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_CALCULATOR as _);
+    pub const COMPUTER: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_COMPUTER as _);
+    pub const AC_SEARCH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AC_SEARCH as _);
+    pub const AC_HOME: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AC_HOME as _);
+    pub const AC_BACK: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AC_BACK as _);
+    pub const AC_FORWARD: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AC_FORWARD as _);
+    pub const AC_STOP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AC_STOP as _);
+    pub const AC_REFRESH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AC_REFRESH as _);
+    pub const AC_BOOKMARKS: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AC_BOOKMARKS as _);
+    pub const BRIGHTNESSDOWN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_BRIGHTNESSDOWN as _);
+    pub const BRIGHTNESSUP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_BRIGHTNESSUP as _);
+    pub const DISPLAYSWITCH: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_DISPLAYSWITCH as _);
+    pub const KBDILLUMTOGGLE: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KBDILLUMTOGGLE as _);
+    pub const KBDILLUMDOWN: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KBDILLUMDOWN as _);
+    pub const KBDILLUMUP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_KBDILLUMUP as _);
+    pub const EJECT: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_EJECT as _);
+    pub const SLEEP: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_SLEEP as _);
+    pub const APP1: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_APP1 as _);
+    pub const APP2: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_APP2 as _);
+    pub const AUDIOREWIND: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AUDIOREWIND as _);
+    pub const AUDIOFASTFORWARD: Scancode = Scancode(SDL_Scancode::SDL_SCANCODE_AUDIOFASTFORWARD as _);
+
+    /// Get a human-readable name for this physical key.
+    /// 
+    /// Returns an empty string if this key does not have a name.
+    #[doc(alias = "SDL_GetScancodeName")]
+    pub fn name(&self) -> String {
+        let get_scancode_name: unsafe extern "C" fn(u32) -> *const std::ffi::c_char = unsafe {
+            mem::transmute(SDL_GetScancodeName as unsafe extern "C" fn(SDL_Scancode) -> *const std::ffi::c_char)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetScancodeName'");
+        unsafe { CStr::from_ptr(get_scancode_name(self.0)).to_string_lossy().into_owned() }
+    }
+
+    /// Get the physical key with the given human-readable name, as returned by [`Scancode::name`].
+    /// 
+    /// Returns [`Option::None`] if the name is not recognized.
+    #[doc(alias = "SDL_GetScancodeFromName")]
+    pub fn from_name(name: &str) -> Option<Scancode> {
+        let name = CString::new(name).ok()?;
+        let get_scancode_from_name: unsafe extern "C" fn(*const std::ffi::c_char) -> u32 = unsafe {
+            mem::transmute(SDL_GetScancodeFromName as unsafe extern "C" fn(*const std::ffi::c_char) -> SDL_Scancode)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetScancodeFromName'");
+        match unsafe { get_scancode_from_name(name.as_ptr()) } {
+            0 => Option::None,
+            scancode => Option::Some(Scancode(scancode))
+        }
+    }
+
+    /// Get the physical key that produces the given key in the current keyboard layout.
+    /// 
+    /// The keyboard layout is only known once the Video subsystem is initialized.
+    #[doc(alias = "SDL_GetScancodeFromKey")]
+    pub fn from_keycode(_events: &SdlSubsystem<Events>, keycode: Keycode) -> Scancode {
+        let get_scancode_from_key: unsafe extern "C" fn(SDL_Keycode) -> u32 = unsafe {
+            mem::transmute(SDL_GetScancodeFromKey as unsafe extern "C" fn(SDL_Keycode) -> SDL_Scancode)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetScancodeFromKey'");
+        Scancode(unsafe { get_scancode_from_key(keycode.0) })
+    }
+}
+
+impl fmt::Display for Scancode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
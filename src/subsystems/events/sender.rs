@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+use crate::SdlError;
+use crate::subsystems::SdlSubsystemFlag;
+use super::event::Event;
+
+/// A handle used to push events from any thread.
+/// 
+/// Obtained via [`SdlSubsystem<Events>::event_sender`](crate::subsystems::SdlSubsystem::event_sender).
+/// Unlike the events subsystem itself, this handle may be cloned and sent to other threads,
+/// as `SDL_PushEvent` is thread-safe.
+#[derive(Debug, Clone)]
+pub struct EventSender {
+    // PhantomData required to make this struct only initializable via `SdlSubsystem<Events>::event_sender`
+    pub(crate) _private: PhantomData<()>
+}
+
+impl EventSender {
+
+    /// Returns an error if the events subsystem has been shut down, including
+    /// when it was only kept alive by a subsystem depending on it.
+    fn check_initialized(&self) -> Result<(), SdlError> {
+        if SdlSubsystemFlag::Events.initialized() {
+            Ok(())
+        } else {
+            Err(SdlError::NotInitialized(String::from("The Events subsystem is not initialized.")))
+        }
+    }
+
+    /// Add an event to the event queue.
+    /// 
+    /// See [`SdlSubsystem<Events>::push_event`](crate::subsystems::SdlSubsystem::push_event).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NotInitialized`]
+    /// - [`SdlError::InvalidValue`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_PushEvent")]
+    pub fn push_event(&self, event: Event) -> Result<bool, SdlError> {
+        self.check_initialized()?;
+        super::push_event(event)
+    }
+
+    /// Add a custom event carrying `value` to the event queue.
+    /// 
+    /// See [`SdlSubsystem<Events>::push_custom_event`](crate::subsystems::SdlSubsystem::push_custom_event).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NotInitialized`]
+    /// - [`SdlError::SysError`]
+    pub fn push_custom_event<T: 'static + Send>(&self, value: T) -> Result<bool, SdlError> {
+        self.check_initialized()?;
+        super::push_custom_event(value)
+    }
+}
@@ -0,0 +1,169 @@
+use std::{ffi::{c_char, CStr, CString}, fmt, mem};
+use semver::Version;
+use crate::{sys::*, SdlError, utils::load_function, subsystems::joystick::HatState};
+use super::{Button, Axis, GameController};
+
+/// The first SDL2 version with Apple SF Symbols names.
+const APPLE_SF_SYMBOLS_VERSION: Version = Version::new(2, 0, 18);
+
+/// The joystick input behind a game controller button or axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControllerBind {
+    /// The button or axis is not mapped.
+    None,
+    Button(u32),
+    Axis(u32),
+    Hat { hat: u32, mask: HatState }
+}
+
+/// `SDL_GameControllerButtonBind`, with the bind type as its raw value since
+/// `SDL_GameControllerBindType` is a Rust enum in the bindings.
+#[repr(C)]
+struct RawBind {
+    bind_type: u32,
+    value: SDL_GameControllerButtonBind__bindgen_ty_1
+}
+
+impl ControllerBind {
+    fn from_ll(raw: RawBind) -> ControllerBind {
+        unsafe {
+            match raw.bind_type {
+                x if x == SDL_GameControllerBindType::SDL_CONTROLLER_BINDTYPE_BUTTON as u32 => ControllerBind::Button(raw.value.button as _),
+                x if x == SDL_GameControllerBindType::SDL_CONTROLLER_BINDTYPE_AXIS as u32 => ControllerBind::Axis(raw.value.axis as _),
+                x if x == SDL_GameControllerBindType::SDL_CONTROLLER_BINDTYPE_HAT as u32 => ControllerBind::Hat {
+                    hat: raw.value.hat.hat as _,
+                    mask: HatState::from(raw.value.hat.hat_mask as u8)
+                },
+                _ => ControllerBind::None
+            }
+        }
+    }
+}
+
+impl Button {
+
+    /// Get the name of this button, as used by game controller mappings.
+    #[doc(alias = "SDL_GameControllerGetStringForButton")]
+    pub fn name(&self) -> &'static str {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetStringForButton'");
+        let name = unsafe { SDL_GameControllerGetStringForButton(self.to_ll()) };
+
+        // SDL returns static strings, which are ASCII.
+        unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default()
+    }
+
+    /// Get the button with the given name, as used by game controller mappings.
+    #[doc(alias = "SDL_GameControllerGetButtonFromString")]
+    pub fn from_name(name: &str) -> Option<Button> {
+        let name = CString::new(name).ok()?;
+        let get_button_from_string: unsafe extern "C" fn(*const c_char) -> i32 = unsafe {
+            mem::transmute(SDL_GameControllerGetButtonFromString as unsafe extern "C" fn(*const c_char) -> SDL_GameControllerButton)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetButtonFromString'");
+        Button::from_raw(unsafe { get_button_from_string(name.as_ptr()) })
+    }
+}
+
+impl Axis {
+
+    /// Get the name of this axis, as used by game controller mappings.
+    #[doc(alias = "SDL_GameControllerGetStringForAxis")]
+    pub fn name(&self) -> &'static str {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetStringForAxis'");
+        let name = unsafe { SDL_GameControllerGetStringForAxis(self.to_ll()) };
+
+        // SDL returns static strings, which are ASCII.
+        unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default()
+    }
+
+    /// Get the axis with the given name, as used by game controller mappings.
+    #[doc(alias = "SDL_GameControllerGetAxisFromString")]
+    pub fn from_name(name: &str) -> Option<Axis> {
+        let name = CString::new(name).ok()?;
+        let get_axis_from_string: unsafe extern "C" fn(*const c_char) -> i32 = unsafe {
+            mem::transmute(SDL_GameControllerGetAxisFromString as unsafe extern "C" fn(*const c_char) -> SDL_GameControllerAxis)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetAxisFromString'");
+        Axis::from_raw(unsafe { get_axis_from_string(name.as_ptr()) })
+    }
+}
+
+impl fmt::Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl GameController<'_> {
+
+    /// Get the joystick input behind a button.
+    #[doc(alias = "SDL_GameControllerGetBindForButton")]
+    pub fn bind_for_button(&self, button: Button) -> ControllerBind {
+        let get_bind_for_button: unsafe extern "C" fn(*mut SDL_GameController, SDL_GameControllerButton) -> RawBind = unsafe {
+            mem::transmute(SDL_GameControllerGetBindForButton as unsafe extern "C" fn(*mut SDL_GameController, SDL_GameControllerButton) -> SDL_GameControllerButtonBind)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetBindForButton'");
+        ControllerBind::from_ll(unsafe { get_bind_for_button(self.raw, button.to_ll()) })
+    }
+
+    /// Get the joystick input behind an axis.
+    #[doc(alias = "SDL_GameControllerGetBindForAxis")]
+    pub fn bind_for_axis(&self, axis: Axis) -> ControllerBind {
+        let get_bind_for_axis: unsafe extern "C" fn(*mut SDL_GameController, SDL_GameControllerAxis) -> RawBind = unsafe {
+            mem::transmute(SDL_GameControllerGetBindForAxis as unsafe extern "C" fn(*mut SDL_GameController, SDL_GameControllerAxis) -> SDL_GameControllerButtonBind)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetBindForAxis'");
+        ControllerBind::from_ll(unsafe { get_bind_for_axis(self.raw, axis.to_ll()) })
+    }
+
+    /// Get the name of the SF Symbols glyph for a button on Apple platforms.
+    /// 
+    /// Returns [`Option::None`] if there is no glyph for the button.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] before SDL 2.0.18.
+    #[doc(alias = "SDL_GameControllerGetAppleSFSymbolsNameForButton")]
+    pub fn apple_sf_symbols_name_for_button(&self, button: Button) -> Result<Option<String>, SdlError> {
+        let get_name: unsafe extern "C" fn(*mut SDL_GameController, SDL_GameControllerButton) -> *const c_char = unsafe {
+            load_function("SDL_GameControllerGetAppleSFSymbolsNameForButton", APPLE_SF_SYMBOLS_VERSION)?
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetAppleSFSymbolsNameForButton'");
+        Ok(symbols_name_from_ll(unsafe { get_name(self.raw, button.to_ll()) }))
+    }
+
+    /// Get the name of the SF Symbols glyph for an axis on Apple platforms.
+    /// 
+    /// Returns [`Option::None`] if there is no glyph for the axis.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] before SDL 2.0.18.
+    #[doc(alias = "SDL_GameControllerGetAppleSFSymbolsNameForAxis")]
+    pub fn apple_sf_symbols_name_for_axis(&self, axis: Axis) -> Result<Option<String>, SdlError> {
+        let get_name: unsafe extern "C" fn(*mut SDL_GameController, SDL_GameControllerAxis) -> *const c_char = unsafe {
+            load_function("SDL_GameControllerGetAppleSFSymbolsNameForAxis", APPLE_SF_SYMBOLS_VERSION)?
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetAppleSFSymbolsNameForAxis'");
+        Ok(symbols_name_from_ll(unsafe { get_name(self.raw, axis.to_ll()) }))
+    }
+}
+
+/// Copies a glyph name owned by the game controller, which is null if there is none.
+fn symbols_name_from_ll(name: *const c_char) -> Option<String> {
+    if name.is_null() {
+        Option::None
+    } else {
+        Option::Some(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+    }
+}
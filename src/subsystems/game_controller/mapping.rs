@@ -0,0 +1,299 @@
+use std::{ffi::CString, fmt, fs, io::Read, path::Path, str::FromStr};
+use crate::{sys::*, SdlError, utils::{get_sys_error, take_sdl_string}, subsystems::{SdlSubsystem, markers, joystick::guid::JoystickGuid}};
+
+/// The device a [`ControllerMapping`] applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MappingGuid {
+    /// Joysticks with this GUID.
+    Joystick(JoystickGuid),
+    /// XInput devices, written as `xinput`.
+    XInput,
+    /// Joysticks without a mapping of their own, written as `default`.
+    Default
+}
+
+impl fmt::Display for MappingGuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingGuid::Joystick(guid) => guid.fmt(f),
+            MappingGuid::XInput => f.write_str("xinput"),
+            MappingGuid::Default => f.write_str("default")
+        }
+    }
+}
+
+impl FromStr for MappingGuid {
+    type Err = SdlError;
+
+    /// Parse a joystick GUID, `xinput` or `default`, ignoring case like SDL.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("xinput") {
+            Ok(MappingGuid::XInput)
+        } else if s.eq_ignore_ascii_case("default") {
+            Ok(MappingGuid::Default)
+        } else {
+            Ok(MappingGuid::Joystick(s.parse()?))
+        }
+    }
+}
+
+impl From<JoystickGuid> for MappingGuid {
+    fn from(value: JoystickGuid) -> Self {
+        MappingGuid::Joystick(value)
+    }
+}
+
+/// A parsed game controller mapping, in SDL's format of
+/// `guid,name,element:input,...,` such as `a:b0` or `leftx:a0`.
+/// 
+/// Fields are kept in order, and every part is validated so that the
+/// mapping is always written back in a form SDL parses the same way.
+/// 
+/// ```
+/// # use sdl2_rs::subsystems::game_controller::mapping::{ControllerMapping, MappingGuid};
+/// let mut mapping: ControllerMapping = "xinput,XInput Controller,a:b0,b:b1,".parse()?;
+/// assert_eq!(mapping.guid, MappingGuid::XInput);
+/// assert_eq!(mapping.name(), "XInput Controller");
+/// assert_eq!(mapping.get("b"), Some("b1"));
+/// 
+/// mapping.set("a", "b2")?;
+/// mapping.set("platform", "Linux")?;
+/// assert_eq!(mapping.to_string(), "xinput,XInput Controller,a:b2,b:b1,platform:Linux,");
+/// 
+/// assert!(mapping.set("x", "b3,y:b4").is_err());
+/// assert!(mapping.set_name("Pad, Wireless").is_err());
+/// assert!("xinput,Pad,a".parse::<ControllerMapping>().is_err());
+/// # Ok::<(), sdl2_rs::SdlError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ControllerMapping {
+    pub guid: MappingGuid,
+    name: String,
+    fields: Vec<(String, String)>
+}
+
+impl ControllerMapping {
+
+    /// Create a mapping without any fields.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `name` contains a comma.
+    pub fn new(guid: MappingGuid, name: &str) -> Result<Self, SdlError> {
+        check_mapping_part("name", name, ",")?;
+        Ok(ControllerMapping { guid, name: String::from(name), fields: Vec::new() })
+    }
+
+    /// Get the name of the controller.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Set the name of the controller.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `name` contains a comma.
+    pub fn set_name(&mut self, name: &str) -> Result<(), SdlError> {
+        check_mapping_part("name", name, ",")?;
+        self.name = String::from(name);
+        Ok(())
+    }
+
+    /// Get the `element:input` fields in order, including fields such as `platform`.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Get the value of a field, such as the input bound to `a`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set the value of a field, replacing any previous value.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `key` is empty, or either contains a comma or colon.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), SdlError> {
+        if key.is_empty() {
+            return Err(SdlError::InvalidValue(String::from("The key of a mapping field must not be empty.")));
+        }
+
+        check_mapping_part("key", key, ",:")?;
+        check_mapping_part("value", value, ",:")?;
+
+        match self.fields.iter_mut().find(|(k, _)| k == key) {
+            Option::Some((_, v)) => *v = String::from(value),
+            Option::None => self.fields.push((String::from(key), String::from(value)))
+        }
+
+        Ok(())
+    }
+
+    /// Remove a field, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let index = self.fields.iter().position(|(k, _)| k == key)?;
+        Option::Some(self.fields.remove(index).1)
+    }
+
+    /// Get the platform this mapping is restricted to, if any.
+    pub fn platform(&self) -> Option<&str> {
+        self.get("platform")
+    }
+}
+
+impl fmt::Display for ControllerMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},", self.guid, self.name)?;
+
+        for (key, value) in &self.fields {
+            write!(f, "{}:{},", key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ControllerMapping {
+    type Err = SdlError;
+
+    /// Parse a mapping in SDL's format.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SdlError::InvalidValue(format!("'{}' is not a game controller mapping.", s));
+
+        let mut parts = s.trim().split(',');
+        let guid = parts.next().ok_or_else(invalid)?.parse()?;
+        let mut mapping = ControllerMapping::new(guid, parts.next().ok_or_else(invalid)?)?;
+
+        for field in parts.filter(|field| !field.is_empty()) {
+            let (key, value) = field.split_once(':').ok_or_else(invalid)?;
+            mapping.set(key, value)?;
+        }
+
+        Ok(mapping)
+    }
+}
+
+/// Check that a part of a mapping contains none of the `separators`.
+fn check_mapping_part(part: &str, value: &str, separators: &str) -> Result<(), SdlError> {
+    match value.chars().find(|&c| separators.contains(c)) {
+        Option::Some(c) => Err(SdlError::InvalidValue(format!("The mapping {} '{}' must not contain '{}'.", part, value, c))),
+        Option::None => Ok(())
+    }
+}
+
+impl SdlSubsystem<markers::GameController> {
+
+    /// Add a mapping in SDL's format, see [`ControllerMapping`].
+    /// 
+    /// Returns true if the mapping was added, or false if it replaced the
+    /// mapping of the same GUID.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NulError`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerAddMapping")]
+    pub fn add_mapping(&self, mapping: &str) -> Result<bool, SdlError> {
+        let mapping = CString::new(mapping)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerAddMapping'");
+        match unsafe { SDL_GameControllerAddMapping(mapping.as_ptr()) } {
+            1 => Ok(true),
+            0 => Ok(false),
+            _ => Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Add the mappings of a `gamecontroller.txt` database, one per line.
+    /// 
+    /// Mappings for other platforms are skipped. Returns the number of mappings added.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if the database is larger than 2 GiB.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerAddMappingsFromRW")]
+    pub fn load_mappings_from_str(&self, mappings: &str) -> Result<u32, SdlError> {
+        // SDL refuses to open a stream over zero bytes.
+        if mappings.is_empty() {
+            return Ok(0);
+        }
+
+        let len = i32::try_from(mappings.len())
+            .map_err(|_| SdlError::InvalidValue(String::from("The mapping database is too large.")))?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RWFromConstMem'");
+        let rw = unsafe { SDL_RWFromConstMem(mappings.as_ptr().cast(), len) };
+        if rw.is_null() {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
+        // The stream is freed by SDL.
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerAddMappingsFromRW'");
+        match unsafe { SDL_GameControllerAddMappingsFromRW(rw, 1) } {
+            count if count >= 0 => Ok(count as _),
+            _ => Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Add the mappings of a `gamecontroller.txt` database read from `reader`,
+    /// see [`SdlSubsystem<GameController>::load_mappings_from_str`](SdlSubsystem::load_mappings_from_str).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::IoError`]
+    /// - [`SdlError::InvalidValue`]
+    /// - [`SdlError::SysError`]
+    pub fn load_mappings_from_read<R: Read>(&self, mut reader: R) -> Result<u32, SdlError> {
+        let mut mappings = String::new();
+        reader.read_to_string(&mut mappings).map_err(|error| SdlError::IoError(error.to_string()))?;
+
+        self.load_mappings_from_str(&mappings)
+    }
+
+    /// Add the mappings of a `gamecontroller.txt` database file,
+    /// see [`SdlSubsystem<GameController>::load_mappings_from_str`](SdlSubsystem::load_mappings_from_str).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::IoError`]
+    /// - [`SdlError::InvalidValue`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerAddMappingsFromFile")]
+    pub fn load_mappings<P: AsRef<Path>>(&self, path: P) -> Result<u32, SdlError> {
+        let mappings = fs::read_to_string(path).map_err(|error| SdlError::IoError(error.to_string()))?;
+
+        self.load_mappings_from_str(&mappings)
+    }
+
+    /// Get the number of mappings known to SDL.
+    #[doc(alias = "SDL_GameControllerNumMappings")]
+    pub fn num_mappings(&self) -> u32 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerNumMappings'");
+        unsafe { SDL_GameControllerNumMappings() }.max(0) as _
+    }
+
+    /// Get a mapping known to SDL, where `mapping_index` is below [`SdlSubsystem<GameController>::num_mappings`](SdlSubsystem::num_mappings).
+    #[doc(alias = "SDL_GameControllerMappingForIndex")]
+    pub fn mapping_for_index(&self, mapping_index: u32) -> Option<String> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerMappingForIndex'");
+        take_sdl_string(unsafe { SDL_GameControllerMappingForIndex(mapping_index as _) }).ok()
+    }
+
+    /// Get the mapping of the given joystick GUID.
+    #[doc(alias = "SDL_GameControllerMappingForGUID")]
+    pub fn mapping_for_guid(&self, guid: JoystickGuid) -> Option<String> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerMappingForGUID'");
+        take_sdl_string(unsafe { SDL_GameControllerMappingForGUID(guid.into()) }).ok()
+    }
+
+    /// Get the mapping of the joystick at `index`.
+    #[doc(alias = "SDL_GameControllerMappingForDeviceIndex")]
+    pub fn mapping_for_device_index(&self, index: u32) -> Option<String> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerMappingForDeviceIndex'");
+        take_sdl_string(unsafe { SDL_GameControllerMappingForDeviceIndex(index as _) }).ok()
+    }
+}
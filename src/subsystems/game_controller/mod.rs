@@ -0,0 +1,469 @@
+use std::{ffi::CStr, marker::PhantomData, mem};
+use crate::{sys::*, SdlError, utils::{get_sys_error, take_sdl_string}};
+use super::{SdlSubsystem, markers, sensor::SensorType};
+
+pub mod mapping;
+pub mod bind;
+
+/// The buttons of a game controller, named after an Xbox controller.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Button {
+    A = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_A as _,
+    B = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_B as _,
+    X = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_X as _,
+    Y = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_Y as _,
+    Back = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_BACK as _,
+    Guide = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_GUIDE as _,
+    Start = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_START as _,
+    LeftStick = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_LEFTSTICK as _,
+    RightStick = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_RIGHTSTICK as _,
+    LeftShoulder = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_LEFTSHOULDER as _,
+    RightShoulder = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_RIGHTSHOULDER as _,
+    DPadUp = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_DPAD_UP as _,
+    DPadDown = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_DPAD_DOWN as _,
+    DPadLeft = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_DPAD_LEFT as _,
+    DPadRight = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_DPAD_RIGHT as _,
+    /// Share (Xbox Series X), microphone (PS5) or capture (Switch Pro) button.
+    Misc1 = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_MISC1 as _,
+    Paddle1 = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_PADDLE1 as _,
+    Paddle2 = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_PADDLE2 as _,
+    Paddle3 = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_PADDLE3 as _,
+    Paddle4 = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_PADDLE4 as _,
+    Touchpad = SDL_GameControllerButton::SDL_CONTROLLER_BUTTON_TOUCHPAD as _
+}
+
+impl Button {
+    /// All buttons, in the order of their values.
+    pub const ALL: [Button; 21] = [
+        Button::A, Button::B, Button::X, Button::Y,
+        Button::Back, Button::Guide, Button::Start,
+        Button::LeftStick, Button::RightStick, Button::LeftShoulder, Button::RightShoulder,
+        Button::DPadUp, Button::DPadDown, Button::DPadLeft, Button::DPadRight,
+        Button::Misc1, Button::Paddle1, Button::Paddle2, Button::Paddle3, Button::Paddle4,
+        Button::Touchpad
+    ];
+
+    /// Get the button with the given value, as reported by controller button events.
+    pub fn from_raw(value: i32) -> Option<Button> {
+        Button::ALL.get(usize::try_from(value).ok()?).copied()
+    }
+
+    pub(crate) fn to_ll(self) -> SDL_GameControllerButton {
+        // Every button has the value of an `SDL_GameControllerButton` variant.
+        unsafe { mem::transmute(self as i32) }
+    }
+}
+
+/// The axes of a game controller, between -32768 and 32767 for sticks
+/// and between 0 and 32767 for triggers.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Axis {
+    LeftX = SDL_GameControllerAxis::SDL_CONTROLLER_AXIS_LEFTX as _,
+    LeftY = SDL_GameControllerAxis::SDL_CONTROLLER_AXIS_LEFTY as _,
+    RightX = SDL_GameControllerAxis::SDL_CONTROLLER_AXIS_RIGHTX as _,
+    RightY = SDL_GameControllerAxis::SDL_CONTROLLER_AXIS_RIGHTY as _,
+    TriggerLeft = SDL_GameControllerAxis::SDL_CONTROLLER_AXIS_TRIGGERLEFT as _,
+    TriggerRight = SDL_GameControllerAxis::SDL_CONTROLLER_AXIS_TRIGGERRIGHT as _
+}
+
+impl Axis {
+    /// All axes, in the order of their values.
+    pub const ALL: [Axis; 6] = [Axis::LeftX, Axis::LeftY, Axis::RightX, Axis::RightY, Axis::TriggerLeft, Axis::TriggerRight];
+
+    /// Get the axis with the given value, as reported by controller axis events.
+    pub fn from_raw(value: i32) -> Option<Axis> {
+        Axis::ALL.get(usize::try_from(value).ok()?).copied()
+    }
+
+    pub(crate) fn to_ll(self) -> SDL_GameControllerAxis {
+        // Every axis has the value of an `SDL_GameControllerAxis` variant.
+        unsafe { mem::transmute(self as i32) }
+    }
+}
+
+/// The model of a game controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameControllerType {
+    Unknown,
+    Xbox360,
+    XboxOne,
+    PS3,
+    PS4,
+    PS5,
+    NintendoSwitchPro,
+    Virtual
+}
+
+impl GameControllerType {
+    pub(crate) fn from_ll(value: u32) -> GameControllerType {
+        match value {
+            x if x == SDL_GameControllerType::SDL_CONTROLLER_TYPE_XBOX360 as u32 => GameControllerType::Xbox360,
+            x if x == SDL_GameControllerType::SDL_CONTROLLER_TYPE_XBOXONE as u32 => GameControllerType::XboxOne,
+            x if x == SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS3 as u32 => GameControllerType::PS3,
+            x if x == SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS4 as u32 => GameControllerType::PS4,
+            x if x == SDL_GameControllerType::SDL_CONTROLLER_TYPE_PS5 as u32 => GameControllerType::PS5,
+            x if x == SDL_GameControllerType::SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_PRO as u32 => GameControllerType::NintendoSwitchPro,
+            x if x == SDL_GameControllerType::SDL_CONTROLLER_TYPE_VIRTUAL as u32 => GameControllerType::Virtual,
+            _ => GameControllerType::Unknown
+        }
+    }
+}
+
+/// The state of a finger on a game controller touchpad, see [`GameController::touchpad_finger`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchpadFinger {
+    pub down: bool,
+    /// Normalized position between 0 and 1.
+    pub x: f32,
+    /// Normalized position between 0 and 1.
+    pub y: f32,
+    pub pressure: f32
+}
+
+/// An opened game controller, closed on drop.
+pub struct GameController<'a> {
+    pub(crate) raw: *mut SDL_GameController,
+    pub(crate) game_controller: PhantomData<&'a SdlSubsystem<markers::GameController>>
+}
+
+impl Drop for GameController<'_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerClose' via GameController drop.");
+        unsafe { SDL_GameControllerClose(self.raw) }
+    }
+}
+
+/// `SDL_GameControllerType` is a Rust enum in the bindings, so functions returning it
+/// are called through this signature to avoid reading an unknown variant.
+type GetGameControllerType<T> = unsafe extern "C" fn(T) -> u32;
+
+impl SdlSubsystem<markers::GameController> {
+
+    /// Returns true if the joystick at `index` is supported by the game controller API.
+    #[doc(alias = "SDL_IsGameController")]
+    pub fn is_game_controller(&self, index: u32) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_IsGameController'");
+        unsafe { SDL_IsGameController(index as _) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Get the name of the game controller at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if the joystick at `index` is not a game controller.
+    #[doc(alias = "SDL_GameControllerNameForIndex")]
+    pub fn name_for_index(&self, index: u32) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerNameForIndex'");
+        let name = unsafe { SDL_GameControllerNameForIndex(index as _) };
+
+        if !name.is_null() {
+            Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the model of the game controller at `index`.
+    #[doc(alias = "SDL_GameControllerTypeForIndex")]
+    pub fn type_for_index(&self, index: u32) -> GameControllerType {
+        let type_for_index: GetGameControllerType<i32> = unsafe {
+            mem::transmute(SDL_GameControllerTypeForIndex as unsafe extern "C" fn(i32) -> SDL_GameControllerType)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerTypeForIndex'");
+        GameControllerType::from_ll(unsafe { type_for_index(index as _) })
+    }
+
+    /// Open the game controller at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerOpen")]
+    pub fn open(&self, index: u32) -> Result<GameController<'_>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerOpen'");
+        let raw = unsafe { SDL_GameControllerOpen(index as _) };
+
+        if !raw.is_null() {
+            Ok(GameController { raw, game_controller: PhantomData })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
+
+impl GameController<'_> {
+
+    /// Get the name of this game controller.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerName")]
+    pub fn name(&self) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerName'");
+        let name = unsafe { SDL_GameControllerName(self.raw) };
+
+        if !name.is_null() {
+            Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the model of this game controller.
+    #[doc(alias = "SDL_GameControllerGetType")]
+    pub fn controller_type(&self) -> GameControllerType {
+        let get_type: GetGameControllerType<*mut SDL_GameController> = unsafe {
+            mem::transmute(SDL_GameControllerGetType as unsafe extern "C" fn(*mut SDL_GameController) -> SDL_GameControllerType)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetType'");
+        GameControllerType::from_ll(unsafe { get_type(self.raw) })
+    }
+
+    /// Get the instance ID of the joystick behind this game controller, which identifies it in events.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickInstanceID")]
+    pub fn instance_id(&self) -> Result<i32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetJoystick'");
+        let joystick = unsafe { SDL_GameControllerGetJoystick(self.raw) };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickInstanceID'");
+        match unsafe { SDL_JoystickInstanceID(joystick) } {
+            -1 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            instance_id => Ok(instance_id)
+        }
+    }
+
+    /// Get the player index of this game controller, if any.
+    #[doc(alias = "SDL_GameControllerGetPlayerIndex")]
+    pub fn player_index(&self) -> Option<u32> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetPlayerIndex'");
+        match unsafe { SDL_GameControllerGetPlayerIndex(self.raw) } {
+            -1 => Option::None,
+            player_index => Option::Some(player_index as _)
+        }
+    }
+
+    /// Set the player index of this game controller, or clear it with [`Option::None`].
+    #[doc(alias = "SDL_GameControllerSetPlayerIndex")]
+    pub fn set_player_index(&mut self, player_index: Option<u32>) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerSetPlayerIndex'");
+        unsafe { SDL_GameControllerSetPlayerIndex(self.raw, player_index.map_or(-1, |index| index as _)) }
+    }
+
+    /// Get the USB vendor ID of this game controller, if available.
+    #[doc(alias = "SDL_GameControllerGetVendor")]
+    pub fn vendor(&self) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetVendor'");
+        match unsafe { SDL_GameControllerGetVendor(self.raw) } {
+            0 => Option::None,
+            vendor => Option::Some(vendor)
+        }
+    }
+
+    /// Get the USB product ID of this game controller, if available.
+    #[doc(alias = "SDL_GameControllerGetProduct")]
+    pub fn product(&self) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetProduct'");
+        match unsafe { SDL_GameControllerGetProduct(self.raw) } {
+            0 => Option::None,
+            product => Option::Some(product)
+        }
+    }
+
+    /// Get the product version of this game controller, if available.
+    #[doc(alias = "SDL_GameControllerGetProductVersion")]
+    pub fn product_version(&self) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetProductVersion'");
+        match unsafe { SDL_GameControllerGetProductVersion(self.raw) } {
+            0 => Option::None,
+            version => Option::Some(version)
+        }
+    }
+
+    /// Get the serial number of this game controller, if available.
+    #[doc(alias = "SDL_GameControllerGetSerial")]
+    pub fn serial(&self) -> Option<String> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetSerial'");
+        let serial = unsafe { SDL_GameControllerGetSerial(self.raw) };
+
+        (!serial.is_null()).then(|| unsafe { CStr::from_ptr(serial) }.to_string_lossy().into_owned())
+    }
+
+    /// Returns true if this game controller is still attached.
+    #[doc(alias = "SDL_GameControllerGetAttached")]
+    pub fn attached(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetAttached'");
+        unsafe { SDL_GameControllerGetAttached(self.raw) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Get the mapping of this game controller, in SDL's mapping string format.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerMapping")]
+    pub fn mapping(&self) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerMapping'");
+        take_sdl_string(unsafe { SDL_GameControllerMapping(self.raw) })
+    }
+
+    /// Returns true if this game controller has the given button.
+    #[doc(alias = "SDL_GameControllerHasButton")]
+    pub fn has_button(&self, button: Button) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerHasButton'");
+        unsafe { SDL_GameControllerHasButton(self.raw, button.to_ll()) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Returns true if a button is pressed.
+    #[doc(alias = "SDL_GameControllerGetButton")]
+    pub fn button(&self, button: Button) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetButton'");
+        unsafe { SDL_GameControllerGetButton(self.raw, button.to_ll()) != 0 }
+    }
+
+    /// Returns true if this game controller has the given axis.
+    #[doc(alias = "SDL_GameControllerHasAxis")]
+    pub fn has_axis(&self, axis: Axis) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerHasAxis'");
+        unsafe { SDL_GameControllerHasAxis(self.raw, axis.to_ll()) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Get the current position of an axis.
+    #[doc(alias = "SDL_GameControllerGetAxis")]
+    pub fn axis(&self, axis: Axis) -> i16 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetAxis'");
+        unsafe { SDL_GameControllerGetAxis(self.raw, axis.to_ll()) }
+    }
+
+    /// Get the number of touchpads of this game controller.
+    #[doc(alias = "SDL_GameControllerGetNumTouchpads")]
+    pub fn num_touchpads(&self) -> u32 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetNumTouchpads'");
+        unsafe { SDL_GameControllerGetNumTouchpads(self.raw) }.max(0) as _
+    }
+
+    /// Get the number of fingers which can be tracked on a touchpad.
+    #[doc(alias = "SDL_GameControllerGetNumTouchpadFingers")]
+    pub fn num_touchpad_fingers(&self, touchpad: u32) -> u32 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetNumTouchpadFingers'");
+        unsafe { SDL_GameControllerGetNumTouchpadFingers(self.raw, touchpad as _) }.max(0) as _
+    }
+
+    /// Get the state of a finger on a touchpad.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerGetTouchpadFinger")]
+    pub fn touchpad_finger(&self, touchpad: u32, finger: u32) -> Result<TouchpadFinger, SdlError> {
+        let (mut state, mut x, mut y, mut pressure) = (0, 0.0, 0.0, 0.0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetTouchpadFinger'");
+        if unsafe { SDL_GameControllerGetTouchpadFinger(self.raw, touchpad as _, finger as _, &mut state, &mut x, &mut y, &mut pressure) } == 0 {
+            Ok(TouchpadFinger { down: state != 0, x, y, pressure })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Returns true if this game controller has the given sensor.
+    #[doc(alias = "SDL_GameControllerHasSensor")]
+    pub fn has_sensor(&self, sensor: SensorType) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerHasSensor'");
+        unsafe { SDL_GameControllerHasSensor(self.raw, sensor.to_ll()) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Set whether a sensor reports data, see [`GameController::sensor_data`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GameControllerSetSensorEnabled")]
+    pub fn set_sensor_enabled(&mut self, sensor: SensorType, enabled: bool) -> Result<(), SdlError> {
+        let enabled = if enabled { SDL_bool::SDL_TRUE } else { SDL_bool::SDL_FALSE };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerSetSensorEnabled'");
+        if unsafe { SDL_GameControllerSetSensorEnabled(self.raw, sensor.to_ll(), enabled) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Returns true if a sensor reports data.
+    #[doc(alias = "SDL_GameControllerIsSensorEnabled")]
+    pub fn is_sensor_enabled(&self, sensor: SensorType) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerIsSensorEnabled'");
+        unsafe { SDL_GameControllerIsSensorEnabled(self.raw, sensor.to_ll()) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Get the current reading of a sensor, see [`SensorType`] for its units.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if the sensor is missing or not enabled.
+    #[doc(alias = "SDL_GameControllerGetSensorData")]
+    pub fn sensor_data(&self, sensor: SensorType) -> Result<[f32; 3], SdlError> {
+        let mut data = [0.0; 3];
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerGetSensorData'");
+        if unsafe { SDL_GameControllerGetSensorData(self.raw, sensor.to_ll(), data.as_mut_ptr(), data.len() as _) } == 0 {
+            Ok(data)
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Start a rumble effect, replacing any previous one.
+    /// 
+    /// A `duration_ms` of zero stops rumbling.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if rumble is not supported.
+    #[doc(alias = "SDL_GameControllerRumble")]
+    pub fn rumble(&mut self, low_frequency: u16, high_frequency: u16, duration_ms: u32) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerRumble'");
+        if unsafe { SDL_GameControllerRumble(self.raw, low_frequency, high_frequency, duration_ms) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Start a rumble effect in the triggers, replacing any previous one.
+    /// 
+    /// A `duration_ms` of zero stops rumbling.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if trigger rumble is not supported.
+    #[doc(alias = "SDL_GameControllerRumbleTriggers")]
+    pub fn rumble_triggers(&mut self, left: u16, right: u16, duration_ms: u32) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerRumbleTriggers'");
+        if unsafe { SDL_GameControllerRumbleTriggers(self.raw, left, right, duration_ms) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Returns true if this game controller has an LED which can be changed.
+    #[doc(alias = "SDL_GameControllerHasLED")]
+    pub fn has_led(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerHasLED'");
+        unsafe { SDL_GameControllerHasLED(self.raw) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Set the color of the LED of this game controller.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if this game controller does not have an LED.
+    #[doc(alias = "SDL_GameControllerSetLED")]
+    pub fn set_led(&mut self, r: u8, g: u8, b: u8) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GameControllerSetLED'");
+        if unsafe { SDL_GameControllerSetLED(self.raw, r, g, b) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
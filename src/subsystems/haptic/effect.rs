@@ -0,0 +1,300 @@
+use std::mem;
+use crate::{sys::*, SdlError};
+
+/// The direction of a haptic effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HapticDirection {
+    /// Hundredths of a degree clockwise from north, where the effect comes from the north at 0.
+    Polar(i32),
+    /// Coordinates (x, y, z) of where the effect comes from, where +x is east, +y is south and +z is up.
+    Cartesian([i32; 3]),
+    /// Hundredths of a degree for the rotation and elevation of where the effect comes from.
+    Spherical([i32; 2]),
+    /// The direction of the first axis of the device, as used by steering wheels.
+    SteeringAxis
+}
+
+impl Default for HapticDirection {
+    fn default() -> Self {
+        HapticDirection::Polar(0)
+    }
+}
+
+impl From<HapticDirection> for SDL_HapticDirection {
+    fn from(value: HapticDirection) -> Self {
+        let (type_, dir) = match value {
+            HapticDirection::Polar(degrees) => (SDL_HAPTIC_POLAR, [degrees, 0, 0]),
+            HapticDirection::Cartesian(dir) => (SDL_HAPTIC_CARTESIAN, dir),
+            HapticDirection::Spherical([rotation, elevation]) => (SDL_HAPTIC_SPHERICAL, [rotation, elevation, 0]),
+            HapticDirection::SteeringAxis => (SDL_HAPTIC_STEERING_AXIS, [0; 3])
+        };
+
+        SDL_HapticDirection { type_: type_ as _, dir }
+    }
+}
+
+/// When and how long a haptic effect plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HapticTiming {
+    /// Duration of the effect in milliseconds, or [`u32::MAX`] to play forever.
+    pub length: u32,
+    /// Delay before starting the effect in milliseconds.
+    pub delay: u16,
+    /// Button which triggers the effect, or zero for none.
+    pub button: u16,
+    /// Minimum time between triggers in milliseconds.
+    pub interval: u16
+}
+
+/// How a haptic effect fades in and out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HapticEnvelope {
+    /// Duration of the fade in, in milliseconds.
+    pub attack_length: u16,
+    /// Level at the start of the fade in.
+    pub attack_level: u16,
+    /// Duration of the fade out, in milliseconds.
+    pub fade_length: u16,
+    /// Level at the end of the fade out.
+    pub fade_level: u16
+}
+
+/// The wave shape of a [`HapticEffect::Periodic`] effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Waveform {
+    Sine,
+    Triangle,
+    SawtoothUp,
+    SawtoothDown
+}
+
+/// The kind of a [`HapticEffect::Condition`] effect, which depends on the position of the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConditionKind {
+    /// Depends on the position of the axes.
+    Spring,
+    /// Depends on the velocity of the axes.
+    Damper,
+    /// Depends on the acceleration of the axes.
+    Inertia,
+    /// Depends on the movement of the axes.
+    Friction
+}
+
+/// A haptic effect, see [`Haptic::new_effect`](super::Haptic::new_effect).
+/// 
+/// Per-axis arrays of condition effects hold the values for the x, y and z axes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HapticEffect {
+    /// A constant force.
+    Constant { direction: HapticDirection, timing: HapticTiming, level: i16, envelope: HapticEnvelope },
+    /// A force following a wave.
+    Periodic {
+        waveform: Waveform,
+        direction: HapticDirection,
+        timing: HapticTiming,
+        /// Period of the wave in milliseconds.
+        period: u16,
+        magnitude: i16,
+        offset: i16,
+        /// Horizontal shift of the wave in hundredths of a degree.
+        phase: u16,
+        envelope: HapticEnvelope
+    },
+    /// A force depending on the state of the axes.
+    Condition {
+        kind: ConditionKind,
+        direction: HapticDirection,
+        timing: HapticTiming,
+        right_sat: [u16; 3],
+        left_sat: [u16; 3],
+        right_coeff: [i16; 3],
+        left_coeff: [i16; 3],
+        deadband: [u16; 3],
+        center: [i16; 3]
+    },
+    /// A force which changes linearly from `start` to `end`.
+    Ramp { direction: HapticDirection, timing: HapticTiming, start: i16, end: i16, envelope: HapticEnvelope },
+    /// Rumble of the large (low frequency) and small (high frequency) motors, as found in game controllers.
+    LeftRight {
+        /// Duration of the effect in milliseconds, or [`u32::MAX`] to play forever.
+        length: u32,
+        large_magnitude: u16,
+        small_magnitude: u16
+    },
+    /// A force following arbitrary samples, interleaved by channel.
+    Custom {
+        direction: HapticDirection,
+        timing: HapticTiming,
+        channels: u8,
+        /// Duration of each sample in milliseconds.
+        period: u16,
+        samples: Vec<u16>,
+        envelope: HapticEnvelope
+    }
+}
+
+impl HapticEffect {
+
+    /// Convert this effect into its SDL representation.
+    /// 
+    /// The samples of [`HapticEffect::Custom`] effects are borrowed from `self`,
+    /// which must outlive any use of the returned value.
+    pub(crate) fn to_ll(&self) -> Result<SDL_HapticEffect, SdlError> {
+        let mut raw: SDL_HapticEffect = unsafe { mem::zeroed() };
+
+        match self {
+            HapticEffect::Constant { direction, timing, level, envelope } => {
+                raw.constant = SDL_HapticConstant {
+                    type_: SDL_HAPTIC_CONSTANT as _,
+                    direction: (*direction).into(),
+                    length: timing.length,
+                    delay: timing.delay,
+                    button: timing.button,
+                    interval: timing.interval,
+                    level: *level,
+                    attack_length: envelope.attack_length,
+                    attack_level: envelope.attack_level,
+                    fade_length: envelope.fade_length,
+                    fade_level: envelope.fade_level
+                };
+            }
+            HapticEffect::Periodic { waveform, direction, timing, period, magnitude, offset, phase, envelope } => {
+                let type_ = match waveform {
+                    Waveform::Sine => SDL_HAPTIC_SINE,
+                    Waveform::Triangle => SDL_HAPTIC_TRIANGLE,
+                    Waveform::SawtoothUp => SDL_HAPTIC_SAWTOOTHUP,
+                    Waveform::SawtoothDown => SDL_HAPTIC_SAWTOOTHDOWN
+                };
+
+                raw.periodic = SDL_HapticPeriodic {
+                    type_: type_ as _,
+                    direction: (*direction).into(),
+                    length: timing.length,
+                    delay: timing.delay,
+                    button: timing.button,
+                    interval: timing.interval,
+                    period: *period,
+                    magnitude: *magnitude,
+                    offset: *offset,
+                    phase: *phase,
+                    attack_length: envelope.attack_length,
+                    attack_level: envelope.attack_level,
+                    fade_length: envelope.fade_length,
+                    fade_level: envelope.fade_level
+                };
+            }
+            HapticEffect::Condition { kind, direction, timing, right_sat, left_sat, right_coeff, left_coeff, deadband, center } => {
+                let type_ = match kind {
+                    ConditionKind::Spring => SDL_HAPTIC_SPRING,
+                    ConditionKind::Damper => SDL_HAPTIC_DAMPER,
+                    ConditionKind::Inertia => SDL_HAPTIC_INERTIA,
+                    ConditionKind::Friction => SDL_HAPTIC_FRICTION
+                };
+
+                raw.condition = SDL_HapticCondition {
+                    type_: type_ as _,
+                    direction: (*direction).into(),
+                    length: timing.length,
+                    delay: timing.delay,
+                    button: timing.button,
+                    interval: timing.interval,
+                    right_sat: *right_sat,
+                    left_sat: *left_sat,
+                    right_coeff: *right_coeff,
+                    left_coeff: *left_coeff,
+                    deadband: *deadband,
+                    center: *center
+                };
+            }
+            HapticEffect::Ramp { direction, timing, start, end, envelope } => {
+                raw.ramp = SDL_HapticRamp {
+                    type_: SDL_HAPTIC_RAMP as _,
+                    direction: (*direction).into(),
+                    length: timing.length,
+                    delay: timing.delay,
+                    button: timing.button,
+                    interval: timing.interval,
+                    start: *start,
+                    end: *end,
+                    attack_length: envelope.attack_length,
+                    attack_level: envelope.attack_level,
+                    fade_length: envelope.fade_length,
+                    fade_level: envelope.fade_level
+                };
+            }
+            HapticEffect::LeftRight { length, large_magnitude, small_magnitude } => {
+                raw.leftright = SDL_HapticLeftRight {
+                    type_: SDL_HAPTIC_LEFTRIGHT as _,
+                    length: *length,
+                    large_magnitude: *large_magnitude,
+                    small_magnitude: *small_magnitude
+                };
+            }
+            HapticEffect::Custom { direction, timing, channels, period, samples, envelope } => {
+                let sample_count = match *channels {
+                    0 => Option::None,
+                    channels if samples.len() % channels as usize == 0 => u16::try_from(samples.len() / channels as usize).ok(),
+                    _ => Option::None
+                };
+
+                let Option::Some(sample_count) = sample_count else {
+                    return Err(SdlError::InvalidValue(format!("{} samples cannot be split into {} channel(s) of at most {} samples.", samples.len(), channels, u16::MAX)));
+                };
+
+                raw.custom = SDL_HapticCustom {
+                    type_: SDL_HAPTIC_CUSTOM as _,
+                    direction: (*direction).into(),
+                    length: timing.length,
+                    delay: timing.delay,
+                    button: timing.button,
+                    interval: timing.interval,
+                    channels: *channels,
+                    period: *period,
+                    samples: sample_count,
+                    // SDL does not write to the samples.
+                    data: samples.as_ptr().cast_mut(),
+                    attack_length: envelope.attack_length,
+                    attack_level: envelope.attack_level,
+                    fade_length: envelope.fade_length,
+                    fade_level: envelope.fade_level
+                };
+            }
+        }
+
+        Ok(raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(channels: u8, samples: Vec<u16>) -> HapticEffect {
+        HapticEffect::Custom {
+            direction: HapticDirection::default(),
+            timing: HapticTiming::default(),
+            channels,
+            period: 10,
+            samples,
+            envelope: HapticEnvelope::default()
+        }
+    }
+
+    #[test]
+    fn custom_samples_split_into_channels() {
+        let effect = custom(2, vec![0; 6]);
+        let raw = effect.to_ll().unwrap();
+
+        let raw = unsafe { raw.custom };
+        assert_eq!(raw.channels, 2);
+        assert_eq!(raw.samples, 3);
+    }
+
+    #[test]
+    fn custom_samples_must_fit_channels() {
+        assert!(matches!(custom(0, vec![0; 4]).to_ll(), Err(SdlError::InvalidValue(_))));
+        assert!(matches!(custom(3, vec![0; 4]).to_ll(), Err(SdlError::InvalidValue(_))));
+        assert!(matches!(custom(1, vec![0; u16::MAX as usize + 1]).to_ll(), Err(SdlError::InvalidValue(_))));
+    }
+}
@@ -0,0 +1,412 @@
+use std::{ffi::CStr, marker::PhantomData};
+use bitmask_enum::bitmask;
+use crate::{sys::*, SdlError, utils::get_sys_error};
+use super::{SdlSubsystem, markers, joystick::Joystick};
+
+pub mod effect;
+use effect::HapticEffect;
+
+/// The features supported by a haptic device.
+#[bitmask(u32)]
+pub enum HapticFeatures {
+    Constant = SDL_HAPTIC_CONSTANT,
+    Sine = SDL_HAPTIC_SINE,
+    LeftRight = SDL_HAPTIC_LEFTRIGHT,
+    Triangle = SDL_HAPTIC_TRIANGLE,
+    SawtoothUp = SDL_HAPTIC_SAWTOOTHUP,
+    SawtoothDown = SDL_HAPTIC_SAWTOOTHDOWN,
+    Ramp = SDL_HAPTIC_RAMP,
+    Spring = SDL_HAPTIC_SPRING,
+    Damper = SDL_HAPTIC_DAMPER,
+    Inertia = SDL_HAPTIC_INERTIA,
+    Friction = SDL_HAPTIC_FRICTION,
+    Custom = SDL_HAPTIC_CUSTOM,
+
+    /// The global gain can be set, see [`Haptic::set_gain`].
+    Gain = SDL_HAPTIC_GAIN,
+    /// Autocentering can be set, see [`Haptic::set_autocenter`].
+    Autocenter = SDL_HAPTIC_AUTOCENTER,
+    /// Effect status can be queried, see [`HapticEffectId::is_playing`].
+    Status = SDL_HAPTIC_STATUS,
+    /// The device can be paused, see [`Haptic::pause`].
+    Pause = SDL_HAPTIC_PAUSE
+}
+
+/// An opened haptic device, closed on drop.
+pub struct Haptic<'a> {
+    pub(crate) raw: *mut SDL_Haptic,
+    pub(crate) haptic: PhantomData<&'a SdlSubsystem<markers::Haptic>>
+}
+
+impl Drop for Haptic<'_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticClose' via Haptic drop.");
+        unsafe { SDL_HapticClose(self.raw) }
+    }
+}
+
+/// An effect uploaded to a haptic device, destroyed on drop.
+pub struct HapticEffectId<'h, 'a> {
+    haptic: &'h Haptic<'a>,
+    id: i32,
+    // SDL keeps the sample pointer of custom effects, so the effect is kept alive with its ID.
+    effect: HapticEffect
+}
+
+impl Drop for HapticEffectId<'_, '_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticDestroyEffect' via HapticEffectId drop.");
+        unsafe { SDL_HapticDestroyEffect(self.haptic.raw, self.id) }
+    }
+}
+
+impl SdlSubsystem<markers::Haptic> {
+
+    /// Get the number of haptic devices attached to the system.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_NumHaptics")]
+    pub fn num_haptics(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_NumHaptics'");
+        result_from_ll(unsafe { SDL_NumHaptics() }).map(|count| count as _)
+    }
+
+    /// Get the name of the haptic device at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticName")]
+    pub fn name_for_index(&self, index: u32) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticName'");
+        let name = unsafe { SDL_HapticName(index as _) };
+
+        if !name.is_null() {
+            Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Open the haptic device at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticOpen")]
+    pub fn open(&self, index: u32) -> Result<Haptic<'_>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticOpen'");
+        haptic_from_ll(unsafe { SDL_HapticOpen(index as _) })
+    }
+
+    /// Returns true if the mouse has haptic capabilities.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_MouseIsHaptic")]
+    pub fn is_mouse_haptic(&self) -> Result<bool, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_MouseIsHaptic'");
+        result_from_ll(unsafe { SDL_MouseIsHaptic() }).map(|haptic| haptic != 0)
+    }
+
+    /// Open the haptic device of the mouse.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if the mouse is not haptic.
+    #[doc(alias = "SDL_HapticOpenFromMouse")]
+    pub fn open_from_mouse(&self) -> Result<Haptic<'_>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticOpenFromMouse'");
+        haptic_from_ll(unsafe { SDL_HapticOpenFromMouse() })
+    }
+
+    /// Returns true if `joystick` has haptic capabilities.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickIsHaptic")]
+    pub fn is_joystick_haptic(&self, joystick: &Joystick) -> Result<bool, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickIsHaptic'");
+        result_from_ll(unsafe { SDL_JoystickIsHaptic(joystick.raw) }).map(|haptic| haptic != 0)
+    }
+
+    /// Open the haptic device of `joystick`, which must stay open while the haptic device is used.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if the joystick is not haptic.
+    #[doc(alias = "SDL_HapticOpenFromJoystick")]
+    pub fn open_from_joystick<'a>(&'a self, joystick: &'a Joystick) -> Result<Haptic<'a>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticOpenFromJoystick'");
+        haptic_from_ll(unsafe { SDL_HapticOpenFromJoystick(joystick.raw) })
+    }
+}
+
+impl<'a> Haptic<'a> {
+
+    /// Get the features supported by this device.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticQuery")]
+    pub fn features(&self) -> Result<HapticFeatures, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticQuery'");
+        match unsafe { SDL_HapticQuery(self.raw) } {
+            0 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            features => Ok(HapticFeatures::from(features))
+        }
+    }
+
+    /// Get the number of effects this device can store.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticNumEffects")]
+    pub fn num_effects(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticNumEffects'");
+        result_from_ll(unsafe { SDL_HapticNumEffects(self.raw) }).map(|count| count as _)
+    }
+
+    /// Get the number of effects this device can play at the same time.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticNumEffectsPlaying")]
+    pub fn num_effects_playing(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticNumEffectsPlaying'");
+        result_from_ll(unsafe { SDL_HapticNumEffectsPlaying(self.raw) }).map(|count| count as _)
+    }
+
+    /// Get the number of axes of this device.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticNumAxes")]
+    pub fn num_axes(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticNumAxes'");
+        result_from_ll(unsafe { SDL_HapticNumAxes(self.raw) }).map(|count| count as _)
+    }
+
+    /// Returns true if this device supports `effect`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if the samples of a custom effect do not fit its channels.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticEffectSupported")]
+    pub fn effect_supported(&self, effect: &HapticEffect) -> Result<bool, SdlError> {
+        let mut raw = effect.to_ll()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticEffectSupported'");
+        result_from_ll(unsafe { SDL_HapticEffectSupported(self.raw, &mut raw) }).map(|supported| supported != 0)
+    }
+
+    /// Upload `effect` to this device, returning an ID to play it with.
+    /// 
+    /// Support for the effect is checked before uploading it, as with
+    /// [`Haptic::effect_supported`], so that unsupported effects are told
+    /// apart from other failures.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if the samples of a custom effect do not fit its channels.
+    /// - [`SdlError::Unsupported`] if this device does not support the effect.
+    /// - [`SdlError::SysError`] if no more effects can be stored.
+    #[doc(alias = "SDL_HapticNewEffect")]
+    pub fn new_effect(&self, effect: HapticEffect) -> Result<HapticEffectId<'_, 'a>, SdlError> {
+        let mut raw = effect.to_ll()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticEffectSupported'");
+        if result_from_ll(unsafe { SDL_HapticEffectSupported(self.raw, &mut raw) })? == 0 {
+            return Err(SdlError::Unsupported(String::from("The haptic device does not support the effect.")));
+        }
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticNewEffect'");
+        let id = result_from_ll(unsafe { SDL_HapticNewEffect(self.raw, &mut raw) })?;
+
+        Ok(HapticEffectId { haptic: self, id, effect })
+    }
+
+    /// Set the global gain of this device, between 0 and 100.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `gain` is above 100.
+    /// - [`SdlError::SysError`] if the device does not support [`HapticFeatures::Gain`].
+    #[doc(alias = "SDL_HapticSetGain")]
+    pub fn set_gain(&self, gain: u8) -> Result<(), SdlError> {
+        check_percentage("gain", gain)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticSetGain'");
+        result_from_ll(unsafe { SDL_HapticSetGain(self.raw, gain as _) }).map(|_| ())
+    }
+
+    /// Set the global autocenter of this device, between 0 (disabled) and 100.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `autocenter` is above 100.
+    /// - [`SdlError::SysError`] if the device does not support [`HapticFeatures::Autocenter`].
+    #[doc(alias = "SDL_HapticSetAutocenter")]
+    pub fn set_autocenter(&self, autocenter: u8) -> Result<(), SdlError> {
+        check_percentage("autocenter", autocenter)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticSetAutocenter'");
+        result_from_ll(unsafe { SDL_HapticSetAutocenter(self.raw, autocenter as _) }).map(|_| ())
+    }
+
+    /// Pause this device, so effects can still be modified but do not play.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if the device does not support [`HapticFeatures::Pause`].
+    #[doc(alias = "SDL_HapticPause")]
+    pub fn pause(&self) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticPause'");
+        result_from_ll(unsafe { SDL_HapticPause(self.raw) }).map(|_| ())
+    }
+
+    /// Unpause this device.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if the device does not support [`HapticFeatures::Pause`].
+    #[doc(alias = "SDL_HapticUnpause")]
+    pub fn unpause(&self) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticUnpause'");
+        result_from_ll(unsafe { SDL_HapticUnpause(self.raw) }).map(|_| ())
+    }
+
+    /// Stop all effects playing on this device.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticStopAll")]
+    pub fn stop_all(&self) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticStopAll'");
+        result_from_ll(unsafe { SDL_HapticStopAll(self.raw) }).map(|_| ())
+    }
+
+    /// Returns true if this device supports simple rumble.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticRumbleSupported")]
+    pub fn rumble_supported(&self) -> Result<bool, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticRumbleSupported'");
+        result_from_ll(unsafe { SDL_HapticRumbleSupported(self.raw) }).map(|supported| supported != 0)
+    }
+
+    /// Initialize this device for simple rumble, see [`Haptic::rumble_play`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if simple rumble is not supported.
+    #[doc(alias = "SDL_HapticRumbleInit")]
+    pub fn rumble_init(&self) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticRumbleInit'");
+        result_from_ll(unsafe { SDL_HapticRumbleInit(self.raw) }).map(|_| ())
+    }
+
+    /// Play a simple rumble with a `strength` between 0 and 1.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `strength` is not between 0 and 1.
+    /// - [`SdlError::SysError`] if [`Haptic::rumble_init`] was not called.
+    #[doc(alias = "SDL_HapticRumblePlay")]
+    pub fn rumble_play(&self, strength: f32, duration_ms: u32) -> Result<(), SdlError> {
+        if !(0.0..=1.0).contains(&strength) {
+            return Err(SdlError::InvalidValue(format!("The rumble strength must be between 0 and 1, but is {}.", strength)));
+        }
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticRumblePlay'");
+        result_from_ll(unsafe { SDL_HapticRumblePlay(self.raw, strength, duration_ms) }).map(|_| ())
+    }
+
+    /// Stop the simple rumble.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticRumbleStop")]
+    pub fn rumble_stop(&self) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticRumbleStop'");
+        result_from_ll(unsafe { SDL_HapticRumbleStop(self.raw) }).map(|_| ())
+    }
+}
+
+impl HapticEffectId<'_, '_> {
+
+    /// Get the effect this ID was last uploaded with.
+    pub fn effect(&self) -> &HapticEffect {
+        &self.effect
+    }
+
+    /// Replace the uploaded effect, which must be of the same kind.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if the samples of a custom effect do not fit its channels.
+    /// - [`SdlError::SysError`] if the effect is of a different kind.
+    #[doc(alias = "SDL_HapticUpdateEffect")]
+    pub fn update(&mut self, effect: HapticEffect) -> Result<(), SdlError> {
+        let mut raw = effect.to_ll()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticUpdateEffect'");
+        result_from_ll(unsafe { SDL_HapticUpdateEffect(self.haptic.raw, self.id, &mut raw) })?;
+
+        self.effect = effect;
+        Ok(())
+    }
+
+    /// Play the effect `iterations` times, or forever if [`None`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticRunEffect")]
+    pub fn run(&self, iterations: Option<u32>) -> Result<(), SdlError> {
+        let iterations = iterations.unwrap_or(SDL_HAPTIC_INFINITY);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticRunEffect'");
+        result_from_ll(unsafe { SDL_HapticRunEffect(self.haptic.raw, self.id, iterations) }).map(|_| ())
+    }
+
+    /// Stop playing the effect.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_HapticStopEffect")]
+    pub fn stop(&self) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticStopEffect'");
+        result_from_ll(unsafe { SDL_HapticStopEffect(self.haptic.raw, self.id) }).map(|_| ())
+    }
+
+    /// Returns true if the effect is playing.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if the device does not support [`HapticFeatures::Status`].
+    #[doc(alias = "SDL_HapticGetEffectStatus")]
+    pub fn is_playing(&self) -> Result<bool, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HapticGetEffectStatus'");
+        result_from_ll(unsafe { SDL_HapticGetEffectStatus(self.haptic.raw, self.id) }).map(|status| status != 0)
+    }
+
+    /// Destroy the effect, freeing its slot on the device.
+    /// 
+    /// This is the same as dropping the ID.
+    #[doc(alias = "SDL_HapticDestroyEffect")]
+    #[inline]
+    pub fn destroy(self) {}
+}
+
+fn haptic_from_ll<'a>(raw: *mut SDL_Haptic) -> Result<Haptic<'a>, SdlError> {
+    if !raw.is_null() {
+        Ok(Haptic { raw, haptic: PhantomData })
+    } else {
+        Err(SdlError::SysError(get_sys_error().unwrap()))
+    }
+}
+
+fn result_from_ll(result: i32) -> Result<i32, SdlError> {
+    if result >= 0 {
+        Ok(result)
+    } else {
+        Err(SdlError::SysError(get_sys_error().unwrap()))
+    }
+}
+
+fn check_percentage(setting: &str, value: u8) -> Result<(), SdlError> {
+    if value <= 100 {
+        Ok(())
+    } else {
+        Err(SdlError::InvalidValue(format!("The haptic {} must be between 0 and 100, but is {}.", setting, value)))
+    }
+}
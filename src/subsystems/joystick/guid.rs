@@ -0,0 +1,101 @@
+use std::{ffi::{CStr, CString}, fmt, str::FromStr};
+use crate::{sys::*, SdlError};
+
+/// A stable identifier for a joystick model, which is the same across runs and devices of the same kind.
+/// 
+/// GUIDs are written as 32 hexadecimal digits, as used by game controller mappings.
+/// 
+/// ```
+/// # use sdl2_rs::subsystems::joystick::guid::{JoystickGuid, JoystickGuidInfo};
+/// let guid: JoystickGuid = "030034125e0400008e02000014010000".parse()?;
+/// assert_eq!(guid.to_string(), "030034125e0400008e02000014010000");
+/// assert_eq!(guid.info(), JoystickGuidInfo { vendor: 0x045e, product: 0x028e, version: 0x0114, crc16: 0x1234 });
+/// 
+/// assert!("030034125e0400008e020000140100".parse::<JoystickGuid>().is_err());
+/// assert!("030034125e0400008e0200001401000g".parse::<JoystickGuid>().is_err());
+/// # Ok::<(), sdl2_rs::SdlError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JoystickGuid(pub [u8; 16]);
+
+/// The fields encoded in a [`JoystickGuid`], see [`JoystickGuid::info`].
+/// 
+/// Fields are zero if the GUID does not contain them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct JoystickGuidInfo {
+    pub vendor: u16,
+    pub product: u16,
+    pub version: u16,
+    pub crc16: u16
+}
+
+impl JoystickGuid {
+    /// Returns true if every byte of this GUID is zero, as SDL returns for invalid devices.
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0; 16]
+    }
+
+    /// Decode the USB vendor and product IDs, product version and name checksum of this GUID.
+    #[doc(alias = "SDL_GetJoystickGUIDInfo")]
+    pub fn info(&self) -> JoystickGuidInfo {
+        // `SDL_GetJoystickGUIDInfo` is newer than the bindings, so its decoding is
+        // reproduced here. The GUID is made of little endian 16-bit words.
+        let word = |i: usize| u16::from_le_bytes([self.0[i * 2], self.0[i * 2 + 1]]);
+        let bus = word(0);
+
+        if word(3) == 0 && word(5) == 0 {
+            // Bus, CRC, vendor, zero, product, zero, version, driver signature and data.
+            JoystickGuidInfo { vendor: word(2), product: word(4), version: word(6), crc16: word(1) }
+        } else if bus < u16::from(b' ') {
+            // Bus, CRC and the start of the joystick name.
+            JoystickGuidInfo { crc16: word(1), ..Default::default() }
+        } else {
+            JoystickGuidInfo::default()
+        }
+    }
+}
+
+impl fmt::Display for JoystickGuid {
+    #[doc(alias = "SDL_JoystickGetGUIDString")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0; 33];
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetGUIDString'");
+        unsafe { SDL_JoystickGetGUIDString((*self).into(), buffer.as_mut_ptr(), buffer.len() as _) };
+
+        f.write_str(&unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_string_lossy())
+    }
+}
+
+impl FromStr for JoystickGuid {
+    type Err = SdlError;
+
+    /// Parse a GUID from 32 hexadecimal digits.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`]
+    #[doc(alias = "SDL_JoystickGetGUIDFromString")]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // SDL silently treats invalid digits as zero, so the string is validated first.
+        if s.len() != 32 || !s.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(SdlError::InvalidValue(format!("'{}' is not a joystick GUID of 32 hexadecimal digits.", s)));
+        }
+
+        let s = CString::new(s)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetGUIDFromString'");
+        Ok(JoystickGuid::from(unsafe { SDL_JoystickGetGUIDFromString(s.as_ptr()) }))
+    }
+}
+
+impl From<SDL_JoystickGUID> for JoystickGuid {
+    fn from(value: SDL_JoystickGUID) -> Self {
+        JoystickGuid(value.data)
+    }
+}
+
+impl From<JoystickGuid> for SDL_JoystickGUID {
+    fn from(value: JoystickGuid) -> Self {
+        SDL_JoystickGUID { data: value.0 }
+    }
+}
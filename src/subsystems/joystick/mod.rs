@@ -0,0 +1,528 @@
+use std::{ffi::{c_char, c_int, CStr}, marker::PhantomData, mem};
+use bitmask_enum::bitmask;
+use semver::Version;
+use crate::{sys::*, SdlError, utils::{get_sys_error, load_function}};
+use super::{SdlSubsystem, markers};
+
+pub mod guid;
+pub mod virtual_joystick;
+use guid::JoystickGuid;
+
+/// The kind of a joystick, as reported by its driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoystickType {
+    Unknown,
+    GameController,
+    Wheel,
+    ArcadeStick,
+    FlightStick,
+    DancePad,
+    Guitar,
+    DrumKit,
+    ArcadePad,
+    Throttle
+}
+
+impl JoystickType {
+    pub(crate) fn from_ll(value: u32) -> JoystickType {
+        match value {
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_GAMECONTROLLER as u32 => JoystickType::GameController,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_WHEEL as u32 => JoystickType::Wheel,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_ARCADE_STICK as u32 => JoystickType::ArcadeStick,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_FLIGHT_STICK as u32 => JoystickType::FlightStick,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_DANCE_PAD as u32 => JoystickType::DancePad,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_GUITAR as u32 => JoystickType::Guitar,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_DRUM_KIT as u32 => JoystickType::DrumKit,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_ARCADE_PAD as u32 => JoystickType::ArcadePad,
+            x if x == SDL_JoystickType::SDL_JOYSTICK_TYPE_THROTTLE as u32 => JoystickType::Throttle,
+            _ => JoystickType::Unknown
+        }
+    }
+
+    pub(crate) fn to_ll(self) -> SDL_JoystickType {
+        match self {
+            JoystickType::Unknown => SDL_JoystickType::SDL_JOYSTICK_TYPE_UNKNOWN,
+            JoystickType::GameController => SDL_JoystickType::SDL_JOYSTICK_TYPE_GAMECONTROLLER,
+            JoystickType::Wheel => SDL_JoystickType::SDL_JOYSTICK_TYPE_WHEEL,
+            JoystickType::ArcadeStick => SDL_JoystickType::SDL_JOYSTICK_TYPE_ARCADE_STICK,
+            JoystickType::FlightStick => SDL_JoystickType::SDL_JOYSTICK_TYPE_FLIGHT_STICK,
+            JoystickType::DancePad => SDL_JoystickType::SDL_JOYSTICK_TYPE_DANCE_PAD,
+            JoystickType::Guitar => SDL_JoystickType::SDL_JOYSTICK_TYPE_GUITAR,
+            JoystickType::DrumKit => SDL_JoystickType::SDL_JOYSTICK_TYPE_DRUM_KIT,
+            JoystickType::ArcadePad => SDL_JoystickType::SDL_JOYSTICK_TYPE_ARCADE_PAD,
+            JoystickType::Throttle => SDL_JoystickType::SDL_JOYSTICK_TYPE_THROTTLE
+        }
+    }
+}
+
+/// The battery level of a joystick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerLevel {
+    Unknown,
+    Empty,
+    Low,
+    Medium,
+    Full,
+    Wired
+}
+
+impl PowerLevel {
+    pub(crate) fn from_ll(value: i32) -> PowerLevel {
+        match value {
+            x if x == SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_EMPTY as i32 => PowerLevel::Empty,
+            x if x == SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_LOW as i32 => PowerLevel::Low,
+            x if x == SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_MEDIUM as i32 => PowerLevel::Medium,
+            x if x == SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_FULL as i32 => PowerLevel::Full,
+            x if x == SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_WIRED as i32 => PowerLevel::Wired,
+            _ => PowerLevel::Unknown
+        }
+    }
+}
+
+/// The position of a joystick hat, where no bits set means centered.
+#[bitmask(u8)]
+pub enum HatState {
+    Up = SDL_HAT_UP as _,
+    Right = SDL_HAT_RIGHT as _,
+    Down = SDL_HAT_DOWN as _,
+    Left = SDL_HAT_LEFT as _,
+
+    RightUp = SDL_HAT_RIGHTUP as _,
+    RightDown = SDL_HAT_RIGHTDOWN as _,
+    LeftUp = SDL_HAT_LEFTUP as _,
+    LeftDown = SDL_HAT_LEFTDOWN as _
+}
+
+/// An opened joystick, closed on drop.
+pub struct Joystick<'a> {
+    pub(crate) raw: *mut SDL_Joystick,
+    pub(crate) joystick: PhantomData<&'a SdlSubsystem<markers::Joystick>>
+}
+
+impl Drop for Joystick<'_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickClose' via Joystick drop.");
+        unsafe { SDL_JoystickClose(self.raw) }
+    }
+}
+
+/// `SDL_JoystickType` is a Rust enum in the bindings, so functions returning it
+/// are called through this signature to avoid reading an unknown variant.
+type GetJoystickType<T> = unsafe extern "C" fn(T) -> u32;
+
+impl SdlSubsystem<markers::Joystick> {
+
+    /// Get the number of joysticks attached to the system.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_NumJoysticks")]
+    pub fn num_joysticks(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_NumJoysticks'");
+        match unsafe { SDL_NumJoysticks() } {
+            count if count >= 0 => Ok(count as _),
+            _ => Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the name of the joystick at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickNameForIndex")]
+    pub fn name_for_index(&self, index: u32) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickNameForIndex'");
+        let name = unsafe { SDL_JoystickNameForIndex(index as _) };
+
+        if !name.is_null() {
+            Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the implementation dependent path of the joystick at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] before SDL 2.24.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickPathForIndex")]
+    pub fn path_for_index(&self, index: u32) -> Result<String, SdlError> {
+        let path_for_index: unsafe extern "C" fn(c_int) -> *const c_char = unsafe {
+            load_function("SDL_JoystickPathForIndex", Version::new(2, 24, 0))?
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickPathForIndex'");
+        let path = unsafe { path_for_index(index as _) };
+
+        if !path.is_null() {
+            Ok(unsafe { CStr::from_ptr(path) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the GUID of the joystick at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickGetDeviceGUID")]
+    pub fn guid_for_index(&self, index: u32) -> Result<JoystickGuid, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetDeviceGUID'");
+        let guid = JoystickGuid::from(unsafe { SDL_JoystickGetDeviceGUID(index as _) });
+
+        if !guid.is_zero() {
+            Ok(guid)
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the instance ID of the joystick at `index`, which identifies it in events
+    /// for as long as it is attached.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickGetDeviceInstanceID")]
+    pub fn instance_id_for_index(&self, index: u32) -> Result<i32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetDeviceInstanceID'");
+        match unsafe { SDL_JoystickGetDeviceInstanceID(index as _) } {
+            -1 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            instance_id => Ok(instance_id)
+        }
+    }
+
+    /// Get the USB vendor ID of the joystick at `index`, if available.
+    #[doc(alias = "SDL_JoystickGetDeviceVendor")]
+    pub fn vendor_for_index(&self, index: u32) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetDeviceVendor'");
+        match unsafe { SDL_JoystickGetDeviceVendor(index as _) } {
+            0 => Option::None,
+            vendor => Option::Some(vendor)
+        }
+    }
+
+    /// Get the USB product ID of the joystick at `index`, if available.
+    #[doc(alias = "SDL_JoystickGetDeviceProduct")]
+    pub fn product_for_index(&self, index: u32) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetDeviceProduct'");
+        match unsafe { SDL_JoystickGetDeviceProduct(index as _) } {
+            0 => Option::None,
+            product => Option::Some(product)
+        }
+    }
+
+    /// Get the product version of the joystick at `index`, if available.
+    #[doc(alias = "SDL_JoystickGetDeviceProductVersion")]
+    pub fn product_version_for_index(&self, index: u32) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetDeviceProductVersion'");
+        match unsafe { SDL_JoystickGetDeviceProductVersion(index as _) } {
+            0 => Option::None,
+            version => Option::Some(version)
+        }
+    }
+
+    /// Get the kind of the joystick at `index`.
+    #[doc(alias = "SDL_JoystickGetDeviceType")]
+    pub fn type_for_index(&self, index: u32) -> JoystickType {
+        let get_device_type: GetJoystickType<i32> = unsafe {
+            mem::transmute(SDL_JoystickGetDeviceType as unsafe extern "C" fn(i32) -> SDL_JoystickType)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetDeviceType'");
+        JoystickType::from_ll(unsafe { get_device_type(index as _) })
+    }
+
+    /// Open the joystick at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickOpen")]
+    pub fn open(&self, index: u32) -> Result<Joystick<'_>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickOpen'");
+        let raw = unsafe { SDL_JoystickOpen(index as _) };
+
+        if !raw.is_null() {
+            Ok(Joystick { raw, joystick: PhantomData })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
+
+impl Joystick<'_> {
+
+    /// Get the name of this joystick.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickName")]
+    pub fn name(&self) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickName'");
+        let name = unsafe { SDL_JoystickName(self.raw) };
+
+        if !name.is_null() {
+            Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the instance ID of this joystick, which identifies it in events.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickInstanceID")]
+    pub fn instance_id(&self) -> Result<i32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickInstanceID'");
+        match unsafe { SDL_JoystickInstanceID(self.raw) } {
+            -1 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            instance_id => Ok(instance_id)
+        }
+    }
+
+    /// Get the GUID of this joystick.
+    #[doc(alias = "SDL_JoystickGetGUID")]
+    pub fn guid(&self) -> JoystickGuid {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetGUID'");
+        JoystickGuid::from(unsafe { SDL_JoystickGetGUID(self.raw) })
+    }
+
+    /// Get the USB vendor ID of this joystick, if available.
+    #[doc(alias = "SDL_JoystickGetVendor")]
+    pub fn vendor(&self) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetVendor'");
+        match unsafe { SDL_JoystickGetVendor(self.raw) } {
+            0 => Option::None,
+            vendor => Option::Some(vendor)
+        }
+    }
+
+    /// Get the USB product ID of this joystick, if available.
+    #[doc(alias = "SDL_JoystickGetProduct")]
+    pub fn product(&self) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetProduct'");
+        match unsafe { SDL_JoystickGetProduct(self.raw) } {
+            0 => Option::None,
+            product => Option::Some(product)
+        }
+    }
+
+    /// Get the product version of this joystick, if available.
+    #[doc(alias = "SDL_JoystickGetProductVersion")]
+    pub fn product_version(&self) -> Option<u16> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetProductVersion'");
+        match unsafe { SDL_JoystickGetProductVersion(self.raw) } {
+            0 => Option::None,
+            version => Option::Some(version)
+        }
+    }
+
+    /// Get the serial number of this joystick, if available.
+    #[doc(alias = "SDL_JoystickGetSerial")]
+    pub fn serial(&self) -> Option<String> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetSerial'");
+        let serial = unsafe { SDL_JoystickGetSerial(self.raw) };
+
+        (!serial.is_null()).then(|| unsafe { CStr::from_ptr(serial) }.to_string_lossy().into_owned())
+    }
+
+    /// Get the kind of this joystick.
+    #[doc(alias = "SDL_JoystickGetType")]
+    pub fn joystick_type(&self) -> JoystickType {
+        let get_type: GetJoystickType<*mut SDL_Joystick> = unsafe {
+            mem::transmute(SDL_JoystickGetType as unsafe extern "C" fn(*mut SDL_Joystick) -> SDL_JoystickType)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetType'");
+        JoystickType::from_ll(unsafe { get_type(self.raw) })
+    }
+
+    /// Returns true if this joystick is still attached.
+    #[doc(alias = "SDL_JoystickGetAttached")]
+    pub fn attached(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetAttached'");
+        unsafe { SDL_JoystickGetAttached(self.raw) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Get the number of axes of this joystick.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickNumAxes")]
+    pub fn num_axes(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickNumAxes'");
+        count_from_ll(unsafe { SDL_JoystickNumAxes(self.raw) })
+    }
+
+    /// Get the number of buttons of this joystick.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickNumButtons")]
+    pub fn num_buttons(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickNumButtons'");
+        count_from_ll(unsafe { SDL_JoystickNumButtons(self.raw) })
+    }
+
+    /// Get the number of hats of this joystick.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickNumHats")]
+    pub fn num_hats(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickNumHats'");
+        count_from_ll(unsafe { SDL_JoystickNumHats(self.raw) })
+    }
+
+    /// Get the number of trackballs of this joystick.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickNumBalls")]
+    pub fn num_balls(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickNumBalls'");
+        count_from_ll(unsafe { SDL_JoystickNumBalls(self.raw) })
+    }
+
+    /// Get the current position of an axis, between -32768 and 32767.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if this joystick does not have the axis.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickGetAxis")]
+    pub fn axis(&self, axis: u32) -> Result<i16, SdlError> {
+        check_index("axis", axis, self.num_axes()?)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetAxis'");
+        Ok(unsafe { SDL_JoystickGetAxis(self.raw, axis as _) })
+    }
+
+    /// Returns true if a button is pressed.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if this joystick does not have the button.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickGetButton")]
+    pub fn button(&self, button: u32) -> Result<bool, SdlError> {
+        check_index("button", button, self.num_buttons()?)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetButton'");
+        Ok(unsafe { SDL_JoystickGetButton(self.raw, button as _) } != 0)
+    }
+
+    /// Get the current position of a hat.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if this joystick does not have the hat.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickGetHat")]
+    pub fn hat(&self, hat: u32) -> Result<HatState, SdlError> {
+        check_index("hat", hat, self.num_hats()?)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetHat'");
+        Ok(HatState::from(unsafe { SDL_JoystickGetHat(self.raw, hat as _) }))
+    }
+
+    /// Get the motion of a trackball since the last call of this function, as (dx, dy).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if this joystick does not have the trackball.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickGetBall")]
+    pub fn ball(&self, ball: u32) -> Result<(i32, i32), SdlError> {
+        check_index("ball", ball, self.num_balls()?)?;
+
+        let (mut dx, mut dy) = (0, 0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickGetBall'");
+        if unsafe { SDL_JoystickGetBall(self.raw, ball as _, &mut dx, &mut dy) } == 0 {
+            Ok((dx, dy))
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the battery level of this joystick.
+    #[doc(alias = "SDL_JoystickCurrentPowerLevel")]
+    pub fn power_level(&self) -> PowerLevel {
+        // `SDL_JoystickPowerLevel` is a Rust enum in the bindings, so the function
+        // is called with the raw return value to avoid reading an unknown variant.
+        let current_power_level: unsafe extern "C" fn(*mut SDL_Joystick) -> i32 = unsafe {
+            mem::transmute(SDL_JoystickCurrentPowerLevel as unsafe extern "C" fn(*mut SDL_Joystick) -> SDL_JoystickPowerLevel)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickCurrentPowerLevel'");
+        PowerLevel::from_ll(unsafe { current_power_level(self.raw) })
+    }
+
+    /// Start a rumble effect, replacing any previous one.
+    /// 
+    /// A `duration_ms` of zero stops rumbling.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if rumble is not supported.
+    #[doc(alias = "SDL_JoystickRumble")]
+    pub fn rumble(&mut self, low_frequency: u16, high_frequency: u16, duration_ms: u32) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickRumble'");
+        if unsafe { SDL_JoystickRumble(self.raw, low_frequency, high_frequency, duration_ms) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Start a rumble effect in the triggers, replacing any previous one.
+    /// 
+    /// A `duration_ms` of zero stops rumbling.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if trigger rumble is not supported.
+    #[doc(alias = "SDL_JoystickRumbleTriggers")]
+    pub fn rumble_triggers(&mut self, left: u16, right: u16, duration_ms: u32) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickRumbleTriggers'");
+        if unsafe { SDL_JoystickRumbleTriggers(self.raw, left, right, duration_ms) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Returns true if this joystick has an LED which can be changed.
+    #[doc(alias = "SDL_JoystickHasLED")]
+    pub fn has_led(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickHasLED'");
+        unsafe { SDL_JoystickHasLED(self.raw) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Set the color of the LED of this joystick.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if this joystick does not have an LED.
+    #[doc(alias = "SDL_JoystickSetLED")]
+    pub fn set_led(&mut self, r: u8, g: u8, b: u8) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickSetLED'");
+        if unsafe { SDL_JoystickSetLED(self.raw, r, g, b) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
+
+fn count_from_ll(count: i32) -> Result<u32, SdlError> {
+    if count >= 0 {
+        Ok(count as _)
+    } else {
+        Err(SdlError::SysError(get_sys_error().unwrap()))
+    }
+}
+
+/// SDL returns zero without an error for out of range controls, so they are checked beforehand.
+fn check_index(control: &str, index: u32, count: u32) -> Result<(), SdlError> {
+    if index < count {
+        Ok(())
+    } else {
+        Err(SdlError::InvalidValue(format!("The joystick has no {} {}, as it only has {}.", control, index, count)))
+    }
+}
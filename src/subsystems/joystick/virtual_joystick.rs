@@ -0,0 +1,274 @@
+use std::{ffi::{c_char, c_int, c_void, CString}, ptr};
+use semver::Version;
+use crate::{sys::*, SdlError, utils::{get_sys_error, load_function}, subsystems::{SdlSubsystem, markers}};
+use super::{Joystick, JoystickType, HatState};
+
+/// `SDL_VirtualJoystickDesc`, which is newer than the bindings.
+#[repr(C)]
+struct SDL_VirtualJoystickDesc {
+    version: u16,
+    type_: u16,
+    naxes: u16,
+    nbuttons: u16,
+    nhats: u16,
+    vendor_id: u16,
+    product_id: u16,
+    padding: u16,
+    button_mask: u32,
+    axis_mask: u32,
+    name: *const c_char,
+    userdata: *mut c_void,
+    update: Option<unsafe extern "C" fn(*mut c_void)>,
+    set_player_index: Option<unsafe extern "C" fn(*mut c_void, c_int)>,
+    rumble: Option<unsafe extern "C" fn(*mut c_void, u16, u16) -> c_int>,
+    rumble_triggers: Option<unsafe extern "C" fn(*mut c_void, u16, u16) -> c_int>,
+    set_led: Option<unsafe extern "C" fn(*mut c_void, u8, u8, u8) -> c_int>,
+    send_effect: Option<unsafe extern "C" fn(*mut c_void, *const c_void, c_int) -> c_int>
+}
+
+/// The version of `SDL_VirtualJoystickDesc` described above.
+const SDL_VIRTUAL_JOYSTICK_DESC_VERSION: u16 = 1;
+
+/// Options of a virtual joystick, attached with [`VirtualJoystickBuilder::attach`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VirtualJoystickBuilder {
+    joystick_type: JoystickType,
+    axes: u32,
+    buttons: u32,
+    hats: u32,
+    name: Option<String>,
+    vendor: Option<u16>,
+    product: Option<u16>
+}
+
+impl VirtualJoystickBuilder {
+
+    /// Start building a virtual joystick without any controls.
+    pub fn new(joystick_type: JoystickType) -> Self {
+        VirtualJoystickBuilder {
+            joystick_type,
+            axes: 0,
+            buttons: 0,
+            hats: 0,
+            name: Option::None,
+            vendor: Option::None,
+            product: Option::None
+        }
+    }
+
+    /// Set the number of axes.
+    pub fn axes(mut self, axes: u32) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// Set the number of buttons.
+    pub fn buttons(mut self, buttons: u32) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Set the number of hats.
+    pub fn hats(mut self, hats: u32) -> Self {
+        self.hats = hats;
+        self
+    }
+
+    /// Set the name reported for the joystick.
+    /// 
+    /// This requires SDL 2.24, see [`VirtualJoystickBuilder::attach`].
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Option::Some(String::from(name));
+        self
+    }
+
+    /// Set the USB vendor ID reported for the joystick.
+    /// 
+    /// This requires SDL 2.24, see [`VirtualJoystickBuilder::attach`].
+    pub fn vendor(mut self, vendor: u16) -> Self {
+        self.vendor = Option::Some(vendor);
+        self
+    }
+
+    /// Set the USB product ID reported for the joystick.
+    /// 
+    /// This requires SDL 2.24, see [`VirtualJoystickBuilder::attach`].
+    pub fn product(mut self, product: u16) -> Self {
+        self.product = Option::Some(product);
+        self
+    }
+
+    /// Attach the virtual joystick and open it.
+    /// 
+    /// The joystick is reported like any other device, including a
+    /// [`Event::JoyDeviceAdded`](crate::subsystems::events::event::Event::JoyDeviceAdded) event.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] if a name, vendor or product is set before SDL 2.24.
+    /// - [`SdlError::InvalidValue`] if a name, vendor or product is set, and there are
+    ///   more than 65535 axes, buttons or hats.
+    /// - [`SdlError::NulError`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickAttachVirtual")]
+    #[doc(alias = "SDL_JoystickAttachVirtualEx")]
+    pub fn attach<'a>(&self, joystick: &'a SdlSubsystem<markers::Joystick>) -> Result<VirtualJoystick<'a>, SdlError> {
+        let index = if self.name.is_some() || self.vendor.is_some() || self.product.is_some() {
+            self.attach_ex()?
+        } else {
+            #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickAttachVirtual'");
+            unsafe {
+                SDL_JoystickAttachVirtual(self.joystick_type.to_ll(), self.axes as _, self.buttons as _, self.hats as _)
+            }
+        };
+
+        if index < 0 {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
+        match joystick.open(index as _) {
+            Ok(joystick) => Ok(VirtualJoystick { joystick }),
+            Err(error) => {
+                #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickDetachVirtual'");
+                unsafe { SDL_JoystickDetachVirtual(index) };
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Attach the virtual joystick with `SDL_JoystickAttachVirtualEx`, returning its index.
+    fn attach_ex(&self) -> Result<c_int, SdlError> {
+        let attach_virtual_ex: unsafe extern "C" fn(*const SDL_VirtualJoystickDesc) -> c_int = unsafe {
+            load_function("SDL_JoystickAttachVirtualEx", Version::new(2, 24, 0))?
+        };
+
+        let count = |control: &str, count: u32| u16::try_from(count).map_err(|_| {
+            SdlError::InvalidValue(format!("A virtual joystick cannot have {} {}.", count, control))
+        });
+
+        // SDL copies the name, so it only has to outlive the call.
+        let name = self.name.as_deref().map(CString::new).transpose()?;
+
+        let desc = SDL_VirtualJoystickDesc {
+            version: SDL_VIRTUAL_JOYSTICK_DESC_VERSION,
+            type_: self.joystick_type.to_ll() as _,
+            naxes: count("axes", self.axes)?,
+            nbuttons: count("buttons", self.buttons)?,
+            nhats: count("hats", self.hats)?,
+            vendor_id: self.vendor.unwrap_or(0),
+            product_id: self.product.unwrap_or(0),
+            padding: 0,
+            button_mask: 0,
+            axis_mask: 0,
+            name: name.as_ref().map_or(ptr::null(), |name| name.as_ptr()),
+            userdata: ptr::null_mut(),
+            update: Option::None,
+            set_player_index: Option::None,
+            rumble: Option::None,
+            rumble_triggers: Option::None,
+            set_led: Option::None,
+            send_effect: Option::None
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickAttachVirtualEx'");
+        Ok(unsafe { attach_virtual_ex(&desc) })
+    }
+}
+
+/// An attached virtual joystick, detached on drop.
+/// 
+/// Values set on the joystick are applied the next time joysticks are updated,
+/// for example when events are pumped.
+pub struct VirtualJoystick<'a> {
+    joystick: Joystick<'a>
+}
+
+impl Drop for VirtualJoystick<'_> {
+    fn drop(&mut self) {
+        let _ = self.detach_ll();
+    }
+}
+
+impl<'a> VirtualJoystick<'a> {
+
+    /// Get the opened joystick, which reads back the values set on this virtual joystick.
+    pub fn joystick(&self) -> &Joystick<'a> {
+        &self.joystick
+    }
+
+    /// Set the position of an axis.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickSetVirtualAxis")]
+    pub fn set_axis(&mut self, axis: u32, value: i16) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickSetVirtualAxis'");
+        if unsafe { SDL_JoystickSetVirtualAxis(self.joystick.raw, axis as _, value) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Set whether a button is pressed.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickSetVirtualButton")]
+    pub fn set_button(&mut self, button: u32, pressed: bool) -> Result<(), SdlError> {
+        let state = if pressed { SDL_PRESSED } else { SDL_RELEASED };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickSetVirtualButton'");
+        if unsafe { SDL_JoystickSetVirtualButton(self.joystick.raw, button as _, state as _) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Set the position of a hat.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickSetVirtualHat")]
+    pub fn set_hat(&mut self, hat: u32, value: HatState) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickSetVirtualHat'");
+        if unsafe { SDL_JoystickSetVirtualHat(self.joystick.raw, hat as _, value.bits()) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Detach this virtual joystick, reporting any error instead of ignoring it on drop.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_JoystickDetachVirtual")]
+    pub fn detach(mut self) -> Result<(), SdlError> {
+        self.detach_ll()
+    }
+
+    fn detach_ll(&mut self) -> Result<(), SdlError> {
+        // Device indices shift as joysticks come and go, so the current index
+        // is looked up from the instance ID, which stays the same.
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickInstanceID'");
+        let instance_id = unsafe { SDL_JoystickInstanceID(self.joystick.raw) };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_NumJoysticks'");
+        let index = (0..unsafe { SDL_NumJoysticks() })
+            .find(|index| unsafe { SDL_JoystickGetDeviceInstanceID(*index) } == instance_id);
+
+        let Option::Some(index) = index else {
+            // The joystick was already detached.
+            return Ok(());
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_JoystickDetachVirtual'");
+        if unsafe { SDL_JoystickDetachVirtual(index) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
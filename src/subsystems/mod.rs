@@ -1,19 +1,20 @@
-use std::{marker::PhantomData, sync::atomic::{AtomicBool, Ordering}};
-use crate::sys::*;
+use std::{marker::PhantomData, rc::Rc, sync::atomic::{AtomicBool, Ordering}};
+use crate::{sys::*, context::ContextState};
 
 pub mod markers;
 
 pub mod timer;
 pub mod audio;
+pub mod video;
+pub mod events;
+pub mod joystick;
+pub mod haptic;
+pub mod game_controller;
+pub mod sensor;
 
-pub(crate) static TIMER_INITIALIZED: AtomicBool = AtomicBool::new(false);
-pub(crate) static AUDIO_INITIALIZED: AtomicBool = AtomicBool::new(false);
-pub(crate) static VIDEO_INITIALIZED: AtomicBool = AtomicBool::new(false);
-pub(crate) static JOYSTICK_INITIALIZED: AtomicBool = AtomicBool::new(false);
-pub(crate) static HAPTIC_INITIALIZED: AtomicBool = AtomicBool::new(false);
-pub(crate) static GAME_CONTROLLER_INITIALIZED: AtomicBool = AtomicBool::new(false);
-pub(crate) static EVENTS_INITIALIZED: AtomicBool = AtomicBool::new(false);
-pub(crate) static SENSOR_INITIALIZED: AtomicBool = AtomicBool::new(false);
+/// Whether audio is initialized with a specific driver, which SDL does not count
+/// as an initialization of the audio subsystem.
+pub(crate) static AUDIO_DRIVER_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -29,48 +30,123 @@ pub enum SdlSubsystemFlag {
 }
 
 impl SdlSubsystemFlag {
-    /// Returns true if the subsystem associated with this flag is initialized.
-    pub fn initialized(&self) -> bool {
-        let initialized = match self {
-            SdlSubsystemFlag::Timer => &TIMER_INITIALIZED,
-            SdlSubsystemFlag::Audio => &AUDIO_INITIALIZED,
-            SdlSubsystemFlag::Video => &VIDEO_INITIALIZED,
-            SdlSubsystemFlag::Joystick => &JOYSTICK_INITIALIZED,
-            SdlSubsystemFlag::Haptic => &HAPTIC_INITIALIZED,
-            SdlSubsystemFlag::GameController => &GAME_CONTROLLER_INITIALIZED,
-            SdlSubsystemFlag::Events => &EVENTS_INITIALIZED,
-            SdlSubsystemFlag::Sensor => &SENSOR_INITIALIZED,
-        };
+    /// All subsystem flags.
+    pub const ALL: [SdlSubsystemFlag; 8] = [
+        SdlSubsystemFlag::Timer,
+        SdlSubsystemFlag::Audio,
+        SdlSubsystemFlag::Video,
+        SdlSubsystemFlag::Joystick,
+        SdlSubsystemFlag::Haptic,
+        SdlSubsystemFlag::GameController,
+        SdlSubsystemFlag::Events,
+        SdlSubsystemFlag::Sensor
+    ];
 
-        initialized.load(Ordering::SeqCst)
+    /// Get the subsystems SDL initializes along with this one, which stay
+    /// initialized for as long as any handle of this subsystem is alive.
+    /// 
+    /// Game controllers depend on joysticks, while joysticks, video and audio
+    /// depend on events. The haptic and sensor subsystems have no dependencies.
+    pub fn dependencies(&self) -> &'static [SdlSubsystemFlag] {
+        match self {
+            SdlSubsystemFlag::GameController => &[SdlSubsystemFlag::Joystick, SdlSubsystemFlag::Events],
+            SdlSubsystemFlag::Joystick |
+            SdlSubsystemFlag::Video |
+            SdlSubsystemFlag::Audio => &[SdlSubsystemFlag::Events],
+            SdlSubsystemFlag::Timer |
+            SdlSubsystemFlag::Haptic |
+            SdlSubsystemFlag::Events |
+            SdlSubsystemFlag::Sensor => &[]
+        }
     }
 
-    /// Returns a reference to the [`AtomicBool`] associated with this flag.
-    pub(crate) fn initialized_raw(&self) -> &AtomicBool {
-        match self {
-            SdlSubsystemFlag::Timer => &TIMER_INITIALIZED,
-            SdlSubsystemFlag::Audio => &AUDIO_INITIALIZED,
-            SdlSubsystemFlag::Video => &VIDEO_INITIALIZED,
-            SdlSubsystemFlag::Joystick => &JOYSTICK_INITIALIZED,
-            SdlSubsystemFlag::Haptic => &HAPTIC_INITIALIZED,
-            SdlSubsystemFlag::GameController => &GAME_CONTROLLER_INITIALIZED,
-            SdlSubsystemFlag::Events => &EVENTS_INITIALIZED,
-            SdlSubsystemFlag::Sensor => &SENSOR_INITIALIZED,
+    /// Returns true if the subsystem associated with this flag is initialized,
+    /// either through a handle of its own or as a dependency of another subsystem.
+    #[doc(alias = "SDL_WasInit")]
+    pub fn initialized(&self) -> bool {
+        if *self == SdlSubsystemFlag::Audio && AUDIO_DRIVER_INITIALIZED.load(Ordering::SeqCst) {
+            return true;
         }
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_WasInit({:?})'", self);
+        unsafe { SDL_WasInit(*self as _) != 0 }
     }
 }
 
+/// Handles of the subsystems initialized together via [`SdlContext::init`](crate::SdlContext::init),
+/// where every requested subsystem is [`Some`].
+#[derive(Default)]
+pub struct SdlSubsystems {
+    pub timer: Option<SdlSubsystem<markers::Timer>>,
+    pub audio: Option<SdlSubsystem<markers::Audio>>,
+    pub video: Option<SdlSubsystem<markers::Video>>,
+    pub joystick: Option<SdlSubsystem<markers::Joystick>>,
+    pub haptic: Option<SdlSubsystem<markers::Haptic>>,
+    pub game_controller: Option<SdlSubsystem<markers::GameController>>,
+    pub events: Option<SdlSubsystem<markers::Events>>,
+    pub sensor: Option<SdlSubsystem<markers::Sensor>>
+}
+
+/// A handle to an initialized subsystem.
+/// 
+/// Every call of an initializer such as [`SdlContext::timer`](crate::SdlContext::timer) holds one
+/// of SDL's references to the subsystem, which is shared by all clones of the returned handle.
+/// The subsystem is quit once every handle holding a reference to it, or to a subsystem
+/// depending on it, is dropped.
+/// 
+/// Handles keep SDL2 itself initialized, even once the [`SdlContext`](crate::SdlContext) is dropped.
+/// Like the context, they are bound to the thread SDL2 was initialized on:
+/// 
+/// ```compile_fail,E0277
+/// # use sdl2_rs::SdlContext;
+/// let context = SdlContext::new()?;
+/// let video = context.video()?;
+/// std::thread::spawn(move || drop(video));
+/// # Ok::<(), sdl2_rs::SdlError>(())
+/// ```
+/// 
+/// Objects obtained from a subsystem, such as windows or joysticks, borrow its handle
+/// and cannot outlive it:
+/// 
+/// ```compile_fail,E0597
+/// # use sdl2_rs::SdlContext;
+/// let context = SdlContext::new()?;
+/// let joystick = {
+///     let subsystem = context.joystick()?;
+///     subsystem.open(0)?
+/// };
+/// # Ok::<(), sdl2_rs::SdlError>(())
+/// ```
 pub struct SdlSubsystem<T: markers::SdlSubsystemMarker> {
     pub(crate) t: PhantomData<T>,
+    pub(crate) reference: Rc<SubsystemReference>
+}
+
+impl<T: markers::SdlSubsystemMarker> SdlSubsystem<T> {
+    pub(crate) fn new(context: &Rc<ContextState>, subsystem: SdlSubsystemFlag, quitter: Option<unsafe extern "C" fn()>) -> Self {
+        SdlSubsystem {
+            t: PhantomData,
+            reference: Rc::new(SubsystemReference { subsystem, quitter, _context: Rc::clone(context) })
+        }
+    }
+}
+
+impl<T: markers::SdlSubsystemMarker> Clone for SdlSubsystem<T> {
+    fn clone(&self) -> Self {
+        SdlSubsystem { t: PhantomData, reference: Rc::clone(&self.reference) }
+    }
+}
+
+/// One of SDL's references to a subsystem, released on drop.
+pub(crate) struct SubsystemReference {
     pub(crate) subsystem: SdlSubsystemFlag,
-    pub(crate) quitter: Option<unsafe extern "C" fn()>
+    pub(crate) quitter: Option<unsafe extern "C" fn()>,
+    // Dropped after the subsystem is quit, so SDL2 is quit last.
+    _context: Rc<ContextState>
 }
 
-impl<T: markers::SdlSubsystemMarker> Drop for SdlSubsystem<T> {
+impl Drop for SubsystemReference {
     fn drop(&mut self) {
-        let initialized = self.subsystem.initialized_raw();
-        initialized.store(false, Ordering::SeqCst);
-
         match self.quitter {
             Some(quitter) => unsafe {
                 #[cfg(feature = "log")] debug!("Calling 'SDL_{:?}Quit' via SdlSubsystem drop.", self.subsystem);
@@ -0,0 +1,251 @@
+use std::{ffi::{c_int, CStr}, marker::PhantomData, mem};
+use semver::Version;
+use crate::{sys::*, SdlError, utils::{get_sys_error, load_function}};
+use super::{SdlSubsystem, markers};
+
+/// The kind of a sensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SensorType {
+    /// A sensor of a kind unknown to this library.
+    Unknown,
+    /// An accelerometer, reporting acceleration in m/s² along the x, y and z axes.
+    Accelerometer,
+    /// A gyroscope, reporting angular speed in rad/s around the x, y and z axes.
+    Gyroscope
+}
+
+impl SensorType {
+    pub(crate) fn from_ll(value: i32) -> SensorType {
+        match value {
+            x if x == SDL_SensorType::SDL_SENSOR_ACCEL as i32 => SensorType::Accelerometer,
+            x if x == SDL_SensorType::SDL_SENSOR_GYRO as i32 => SensorType::Gyroscope,
+            _ => SensorType::Unknown
+        }
+    }
+
+    pub(crate) fn to_ll(self) -> SDL_SensorType {
+        match self {
+            SensorType::Unknown => SDL_SensorType::SDL_SENSOR_UNKNOWN,
+            SensorType::Accelerometer => SDL_SensorType::SDL_SENSOR_ACCEL,
+            SensorType::Gyroscope => SDL_SensorType::SDL_SENSOR_GYRO
+        }
+    }
+}
+
+/// An opened sensor, closed on drop.
+pub struct Sensor<'a> {
+    pub(crate) raw: *mut SDL_Sensor,
+    pub(crate) sensor: PhantomData<&'a SdlSubsystem<markers::Sensor>>
+}
+
+impl Drop for Sensor<'_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorClose' via Sensor drop.");
+        unsafe { SDL_SensorClose(self.raw) }
+    }
+}
+
+/// `SDL_SensorType` is a Rust enum in the bindings, so functions returning it
+/// are called through this signature to avoid reading an unknown variant.
+type GetSensorType<T> = unsafe extern "C" fn(T) -> i32;
+
+impl SdlSubsystem<markers::Sensor> {
+
+    /// Get the number of sensors attached to the system.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_NumSensors")]
+    pub fn num_sensors(&self) -> Result<u32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_NumSensors'");
+        match unsafe { SDL_NumSensors() } {
+            count if count >= 0 => Ok(count as _),
+            _ => Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the name of the sensor at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetDeviceName")]
+    pub fn name_for_index(&self, index: u32) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetDeviceName'");
+        let name = unsafe { SDL_SensorGetDeviceName(index as _) };
+
+        if !name.is_null() {
+            Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the kind of the sensor at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetDeviceType")]
+    pub fn type_for_index(&self, index: u32) -> Result<SensorType, SdlError> {
+        let type_for_index: GetSensorType<i32> = unsafe {
+            mem::transmute(SDL_SensorGetDeviceType as unsafe extern "C" fn(i32) -> SDL_SensorType)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetDeviceType'");
+        sensor_type_from_ll(unsafe { type_for_index(index as _) })
+    }
+
+    /// Get the platform dependent kind of the sensor at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetDeviceNonPortableType")]
+    pub fn non_portable_type_for_index(&self, index: u32) -> Result<i32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetDeviceNonPortableType'");
+        match unsafe { SDL_SensorGetDeviceNonPortableType(index as _) } {
+            -1 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            kind => Ok(kind)
+        }
+    }
+
+    /// Get the instance ID of the sensor at `index`, which is unique while the sensor is attached.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetDeviceInstanceID")]
+    pub fn instance_id_for_index(&self, index: u32) -> Result<i32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetDeviceInstanceID'");
+        match unsafe { SDL_SensorGetDeviceInstanceID(index as _) } {
+            -1 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            id => Ok(id)
+        }
+    }
+
+    /// Open the sensor at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorOpen")]
+    pub fn open(&self, index: u32) -> Result<Sensor<'_>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorOpen'");
+        let raw = unsafe { SDL_SensorOpen(index as _) };
+
+        if !raw.is_null() {
+            Ok(Sensor { raw, sensor: PhantomData })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Update the readings of all opened sensors.
+    /// 
+    /// This is done automatically while events are pumped.
+    #[doc(alias = "SDL_SensorUpdate")]
+    pub fn update(&self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorUpdate'");
+        unsafe { SDL_SensorUpdate() }
+    }
+}
+
+impl Sensor<'_> {
+
+    /// Get the name of this sensor.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetName")]
+    pub fn name(&self) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetName'");
+        let name = unsafe { SDL_SensorGetName(self.raw) };
+
+        if !name.is_null() {
+            Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the kind of this sensor.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetType")]
+    pub fn sensor_type(&self) -> Result<SensorType, SdlError> {
+        let sensor_type: GetSensorType<*mut SDL_Sensor> = unsafe {
+            mem::transmute(SDL_SensorGetType as unsafe extern "C" fn(*mut SDL_Sensor) -> SDL_SensorType)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetType'");
+        sensor_type_from_ll(unsafe { sensor_type(self.raw) })
+    }
+
+    /// Get the platform dependent kind of this sensor.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetNonPortableType")]
+    pub fn non_portable_type(&self) -> Result<i32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetNonPortableType'");
+        match unsafe { SDL_SensorGetNonPortableType(self.raw) } {
+            -1 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            kind => Ok(kind)
+        }
+    }
+
+    /// Get the instance ID of this sensor.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetInstanceID")]
+    pub fn instance_id(&self) -> Result<i32, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetInstanceID'");
+        match unsafe { SDL_SensorGetInstanceID(self.raw) } {
+            -1 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            id => Ok(id)
+        }
+    }
+
+    /// Get the current reading of this sensor, see [`SensorType`] for its units.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetData")]
+    pub fn data(&self) -> Result<[f32; 3], SdlError> {
+        let mut data = [0.0; 3];
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetData'");
+        if unsafe { SDL_SensorGetData(self.raw, data.as_mut_ptr(), data.len() as _) } == 0 {
+            Ok(data)
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the current reading of this sensor with the time it was taken at in microseconds,
+    /// see [`SensorType`] for its units.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] before SDL 2.26.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SensorGetDataWithTimestamp")]
+    pub fn data_with_timestamp(&self) -> Result<(u64, [f32; 3]), SdlError> {
+        let get_data_with_timestamp: unsafe extern "C" fn(*mut SDL_Sensor, *mut u64, *mut f32, c_int) -> c_int = unsafe {
+            load_function("SDL_SensorGetDataWithTimestamp", Version::new(2, 26, 0))?
+        };
+        let (mut timestamp, mut data) = (0, [0.0; 3]);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SensorGetDataWithTimestamp'");
+        if unsafe { get_data_with_timestamp(self.raw, &mut timestamp, data.as_mut_ptr(), data.len() as _) } == 0 {
+            Ok((timestamp, data))
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
+
+fn sensor_type_from_ll(value: i32) -> Result<SensorType, SdlError> {
+    if value == SDL_SensorType::SDL_SENSOR_INVALID as i32 {
+        Err(SdlError::SysError(get_sys_error().unwrap()))
+    } else {
+        Ok(SensorType::from_ll(value))
+    }
+}
@@ -1,9 +1,24 @@
-use std::time::Duration;
-use super::{SdlSubsystem, markers::Timer};
-use crate::sys::*;
+use std::{marker::PhantomData, time::Duration};
+use super::{SdlSubsystem, SdlSubsystemFlag, markers::Timer};
+use crate::{sys::*, SdlError};
+
+/// A handle used to read SDL's clocks from any thread.
+/// 
+/// Obtained via [`SdlSubsystem<Timer>::clock`]. Unlike the timer subsystem itself, this
+/// handle may be cloned and sent to other threads, as SDL's clock functions are thread-safe.
+#[derive(Debug, Clone)]
+pub struct Clock {
+    // PhantomData required to make this struct only initializable via `SdlSubsystem<Timer>::clock`
+    _private: PhantomData<()>
+}
 
 impl SdlSubsystem<Timer> {
 
+    /// Get a [`Clock`], which can read SDL's clocks from any thread.
+    pub fn clock(&self) -> Clock {
+        Clock { _private: PhantomData }
+    }
+
     /// Get a [`Duration`] representing the amount of time since the [`SdlContext`] was initialized.
     /// 
     /// This value wraps if the program runs for more that ~49 days.
@@ -47,4 +62,65 @@ impl SdlSubsystem<Timer> {
     /// See [`SDL_RemoveTimer`](https://github.com/libsdl-org/SDL/blob/SDL2/include/SDL_timer.h).
     #[doc(alias = "SDL_RemoveTimer")]
     pub fn remove_timer(&self) { todo!() }
-}
\ No newline at end of file
+}
+
+impl Clock {
+
+    /// Returns an error if the timer subsystem has been shut down.
+    fn check_initialized(&self) -> Result<(), SdlError> {
+        if SdlSubsystemFlag::Timer.initialized() {
+            Ok(())
+        } else {
+            Err(SdlError::NotInitialized(String::from("The Timer subsystem is not initialized.")))
+        }
+    }
+
+    /// See [`SdlSubsystem<Timer>::get_ticks`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NotInitialized`]
+    #[doc(alias = "SDL_GetTicks")]
+    pub fn get_ticks(&self) -> Result<Duration, SdlError> {
+        self.check_initialized()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetTicks()'");
+        Ok(Duration::from_millis(unsafe { SDL_GetTicks() } as _))
+    }
+
+    /// See [`SdlSubsystem<Timer>::get_performance_counter`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NotInitialized`]
+    #[doc(alias = "SDL_GetPerformanceCounter")]
+    pub fn get_performance_counter(&self) -> Result<u64, SdlError> {
+        self.check_initialized()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetPerformanceCounter()'");
+        Ok(unsafe { SDL_GetPerformanceCounter() })
+    }
+
+    /// See [`SdlSubsystem<Timer>::get_performance_freq`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NotInitialized`]
+    #[doc(alias = "SDL_GetPerformanceFrequency")]
+    pub fn get_performance_freq(&self) -> Result<u64, SdlError> {
+        self.check_initialized()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetPerformanceFrequency()'");
+        Ok(unsafe { SDL_GetPerformanceFrequency() })
+    }
+
+    /// See [`SdlSubsystem<Timer>::delay`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NotInitialized`]
+    #[doc(alias = "SDL_Delay")]
+    pub fn delay(&self, ms: u32) -> Result<(), SdlError> {
+        self.check_initialized()?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_Delay()'");
+        unsafe { SDL_Delay(ms) };
+        Ok(())
+    }
+}
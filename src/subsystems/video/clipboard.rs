@@ -0,0 +1,101 @@
+use std::ffi::{c_char, c_int, CString};
+use semver::Version;
+use crate::{sys::*, SdlError, utils::{get_sys_error, load_function, take_sdl_string}, subsystems::{SdlSubsystem, markers::Video}};
+
+/// The first SDL2 version with primary selection support.
+const PRIMARY_SELECTION_VERSION: Version = Version::new(2, 26, 0);
+
+impl SdlSubsystem<Video> {
+
+    /// Put text into the clipboard.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NulError`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SetClipboardText")]
+    pub fn set_clipboard_text(&self, text: &str) -> Result<(), SdlError> {
+        let text = CString::new(text)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetClipboardText'");
+        if unsafe { SDL_SetClipboardText(text.as_ptr()) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the text in the clipboard.
+    /// 
+    /// Returns an empty string if the clipboard does not contain text.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GetClipboardText")]
+    pub fn clipboard_text(&self) -> Result<String, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetClipboardText'");
+        let text = unsafe { SDL_GetClipboardText() };
+
+        take_sdl_string(text)
+    }
+
+    /// Returns true if the clipboard contains non-empty text.
+    #[doc(alias = "SDL_HasClipboardText")]
+    pub fn has_clipboard_text(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HasClipboardText'");
+        unsafe { SDL_HasClipboardText() == SDL_bool::SDL_TRUE }
+    }
+
+    /// Put text into the primary selection, as used by middle-click paste on X11 and Wayland.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] before SDL 2.26.
+    /// - [`SdlError::NulError`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SetPrimarySelectionText")]
+    pub fn set_primary_selection_text(&self, text: &str) -> Result<(), SdlError> {
+        let set_primary_selection_text: unsafe extern "C" fn(*const c_char) -> c_int = unsafe {
+            load_function("SDL_SetPrimarySelectionText", PRIMARY_SELECTION_VERSION)?
+        };
+        let text = CString::new(text)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetPrimarySelectionText'");
+        if unsafe { set_primary_selection_text(text.as_ptr()) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the text in the primary selection.
+    /// 
+    /// Returns an empty string if the primary selection does not contain text.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] before SDL 2.26.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GetPrimarySelectionText")]
+    pub fn primary_selection_text(&self) -> Result<String, SdlError> {
+        let get_primary_selection_text: unsafe extern "C" fn() -> *mut c_char = unsafe {
+            load_function("SDL_GetPrimarySelectionText", PRIMARY_SELECTION_VERSION)?
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetPrimarySelectionText'");
+        let text = unsafe { get_primary_selection_text() };
+
+        take_sdl_string(text)
+    }
+
+    /// Returns true if the primary selection contains non-empty text.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::Unsupported`] before SDL 2.26.
+    #[doc(alias = "SDL_HasPrimarySelectionText")]
+    pub fn has_primary_selection_text(&self) -> Result<bool, SdlError> {
+        let has_primary_selection_text: unsafe extern "C" fn() -> SDL_bool = unsafe {
+            load_function("SDL_HasPrimarySelectionText", PRIMARY_SELECTION_VERSION)?
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HasPrimarySelectionText'");
+        Ok(unsafe { has_primary_selection_text() } == SDL_bool::SDL_TRUE)
+    }
+}
@@ -0,0 +1,140 @@
+use std::marker::PhantomData;
+use crate::{sys::*, SdlError, utils::get_sys_error, subsystems::{SdlSubsystem, markers::Video}};
+use super::{surface::Surface, mouse::MouseUtil};
+
+/// Cursors provided by the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemCursor {
+    Arrow,
+    IBeam,
+    Wait,
+    Crosshair,
+    WaitArrow,
+    SizeNWSE,
+    SizeNESW,
+    SizeWE,
+    SizeNS,
+    SizeAll,
+    No,
+    Hand
+}
+
+impl From<SystemCursor> for SDL_SystemCursor {
+    fn from(value: SystemCursor) -> Self {
+        match value {
+            SystemCursor::Arrow => SDL_SystemCursor::SDL_SYSTEM_CURSOR_ARROW,
+            SystemCursor::IBeam => SDL_SystemCursor::SDL_SYSTEM_CURSOR_IBEAM,
+            SystemCursor::Wait => SDL_SystemCursor::SDL_SYSTEM_CURSOR_WAIT,
+            SystemCursor::Crosshair => SDL_SystemCursor::SDL_SYSTEM_CURSOR_CROSSHAIR,
+            SystemCursor::WaitArrow => SDL_SystemCursor::SDL_SYSTEM_CURSOR_WAITARROW,
+            SystemCursor::SizeNWSE => SDL_SystemCursor::SDL_SYSTEM_CURSOR_SIZENWSE,
+            SystemCursor::SizeNESW => SDL_SystemCursor::SDL_SYSTEM_CURSOR_SIZENESW,
+            SystemCursor::SizeWE => SDL_SystemCursor::SDL_SYSTEM_CURSOR_SIZEWE,
+            SystemCursor::SizeNS => SDL_SystemCursor::SDL_SYSTEM_CURSOR_SIZENS,
+            SystemCursor::SizeAll => SDL_SystemCursor::SDL_SYSTEM_CURSOR_SIZEALL,
+            SystemCursor::No => SDL_SystemCursor::SDL_SYSTEM_CURSOR_NO,
+            SystemCursor::Hand => SDL_SystemCursor::SDL_SYSTEM_CURSOR_HAND
+        }
+    }
+}
+
+/// A mouse cursor, freed on drop.
+/// 
+/// Dropping the active cursor restores the default cursor.
+pub struct Cursor<'a> {
+    pub(crate) raw: *mut SDL_Cursor,
+    pub(crate) video: PhantomData<&'a SdlSubsystem<Video>>
+}
+
+impl Drop for Cursor<'_> {
+    fn drop(&mut self) {
+        // SDL ignores requests to free the default cursor, so this is also
+        // sound for cursors returned by `Cursor::default_cursor`.
+        #[cfg(feature = "log")] debug!("Calling 'SDL_FreeCursor' via Cursor drop.");
+        unsafe { SDL_FreeCursor(self.raw) }
+    }
+}
+
+impl<'a> Cursor<'a> {
+
+    /// Create one of the cursors provided by the system.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_CreateSystemCursor")]
+    pub fn from_system(_video: &'a SdlSubsystem<Video>, cursor: SystemCursor) -> Result<Cursor<'a>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_CreateSystemCursor'");
+        let raw = unsafe { SDL_CreateSystemCursor(cursor.into()) };
+
+        Cursor::from_ll(raw)
+    }
+
+    /// Create a color cursor from a surface, with its hot spot at (`hot_x`, `hot_y`).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_CreateColorCursor")]
+    pub fn from_surface(_video: &'a SdlSubsystem<Video>, surface: &Surface, hot_x: i32, hot_y: i32) -> Result<Cursor<'a>, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_CreateColorCursor'");
+        let raw = unsafe { SDL_CreateColorCursor(surface.raw, hot_x, hot_y) };
+
+        Cursor::from_ll(raw)
+    }
+
+    /// Create a monochrome cursor from bitmaps with one bit per pixel, most significant bit first,
+    /// with its hot spot at (`hot_x`, `hot_y`).
+    /// 
+    /// Each pixel is white if its `data` and `mask` bits are (0, 1), black for (1, 1),
+    /// transparent for (0, 0) and inverted (if possible) for (1, 0).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`] if `width` is not a multiple of 8, or the
+    ///   bitmaps are not `width / 8 * height` bytes long.
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_CreateCursor")]
+    pub fn from_bitmap(_video: &'a SdlSubsystem<Video>, data: &[u8], mask: &[u8], width: u32, height: u32, hot_x: i32, hot_y: i32) -> Result<Cursor<'a>, SdlError> {
+        let Option::Some(len) = (width as usize / 8).checked_mul(height as usize) else {
+            return Err(SdlError::InvalidValue(format!("Cursor size {}x{} is too large.", width, height)));
+        };
+        if width % 8 != 0 || data.len() != len || mask.len() != len {
+            return Err(SdlError::InvalidValue(format!("Cursor bitmaps must be {} bytes long, with a width that is a multiple of 8.", len)));
+        }
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_CreateCursor'");
+        let raw = unsafe { SDL_CreateCursor(data.as_ptr(), mask.as_ptr(), width as _, height as _, hot_x, hot_y) };
+
+        Cursor::from_ll(raw)
+    }
+
+    /// Get the default cursor of the system.
+    /// 
+    /// Returns [`Option::None`] if the video driver does not support cursors.
+    #[doc(alias = "SDL_GetDefaultCursor")]
+    pub fn default_cursor(_video: &'a SdlSubsystem<Video>) -> Option<Cursor<'a>> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetDefaultCursor'");
+        let raw = unsafe { SDL_GetDefaultCursor() };
+
+        // SDL does not set an error message here, so there is nothing to report.
+        (!raw.is_null()).then_some(Cursor { raw, video: PhantomData })
+    }
+
+    fn from_ll(raw: *mut SDL_Cursor) -> Result<Cursor<'a>, SdlError> {
+        if !raw.is_null() {
+            Ok(Cursor { raw, video: PhantomData })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
+
+impl<'a> MouseUtil<'a> {
+
+    /// Set the active cursor.
+    /// 
+    /// The cursor must outlive its use, as dropping it restores the default cursor.
+    #[doc(alias = "SDL_SetCursor")]
+    pub fn set_cursor(&self, cursor: &Cursor<'a>) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetCursor'");
+        unsafe { SDL_SetCursor(cursor.raw) }
+    }
+}
@@ -0,0 +1,175 @@
+use std::{ffi::CString, ptr};
+use bitmask_enum::bitmask;
+use crate::{sys::*, SdlError, utils::{get_sys_error, check_init_thread}};
+use super::window::Window;
+
+/// The kind of a message box, which determines its icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageBoxKind {
+    Error,
+    Warning,
+    Information
+}
+
+impl MessageBoxKind {
+    fn to_ll(self) -> u32 {
+        match self {
+            MessageBoxKind::Error => SDL_MessageBoxFlags::SDL_MESSAGEBOX_ERROR as _,
+            MessageBoxKind::Warning => SDL_MessageBoxFlags::SDL_MESSAGEBOX_WARNING as _,
+            MessageBoxKind::Information => SDL_MessageBoxFlags::SDL_MESSAGEBOX_INFORMATION as _
+        }
+    }
+}
+
+/// Flags of a message box button, see [`MessageBoxBuilder::button`].
+#[bitmask(u32)]
+pub enum MessageBoxButtonFlags {
+    ReturnKeyDefault = SDL_MessageBoxButtonFlags::SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT as _,
+    EscapeKeyDefault = SDL_MessageBoxButtonFlags::SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT as _
+}
+
+/// The colors of a message box, as (r, g, b) triples.
+/// 
+/// Not every platform supports custom colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageBoxColorScheme {
+    pub background: (u8, u8, u8),
+    pub text: (u8, u8, u8),
+    pub button_border: (u8, u8, u8),
+    pub button_background: (u8, u8, u8),
+    pub button_selected: (u8, u8, u8)
+}
+
+impl From<MessageBoxColorScheme> for SDL_MessageBoxColorScheme {
+    fn from(value: MessageBoxColorScheme) -> Self {
+        let color = |(r, g, b)| SDL_MessageBoxColor { r, g, b };
+
+        // The order matches `SDL_MessageBoxColorType`.
+        SDL_MessageBoxColorScheme {
+            colors: [
+                color(value.background),
+                color(value.text),
+                color(value.button_border),
+                color(value.button_background),
+                color(value.button_selected)
+            ]
+        }
+    }
+}
+
+/// Show a modal message box with an OK button.
+/// 
+/// This may be called before SDL2 is initialized, and blocks until the message box is closed.
+/// 
+/// ### Errors
+/// - [`SdlError::WrongThread`] if SDL2 is initialized on another thread.
+/// - [`SdlError::NulError`]
+/// - [`SdlError::SysError`]
+#[doc(alias = "SDL_ShowSimpleMessageBox")]
+pub fn show_simple_message_box(kind: MessageBoxKind, title: &str, message: &str, window: Option<&Window>) -> Result<(), SdlError> {
+    check_init_thread("SDL_ShowSimpleMessageBox")?;
+
+    let title = CString::new(title)?;
+    let message = CString::new(message)?;
+    let window = window.map_or(ptr::null_mut(), |window| window.raw);
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_ShowSimpleMessageBox'");
+    if unsafe { SDL_ShowSimpleMessageBox(kind.to_ll(), title.as_ptr(), message.as_ptr(), window) } == 0 {
+        Ok(())
+    } else {
+        Err(SdlError::SysError(get_sys_error().unwrap()))
+    }
+}
+
+/// A modal message box with custom buttons.
+pub struct MessageBoxBuilder<'w, 'a> {
+    kind: MessageBoxKind,
+    title: String,
+    message: String,
+    window: Option<&'w Window<'a>>,
+    buttons: Vec<(MessageBoxButtonFlags, i32, String)>,
+    color_scheme: Option<MessageBoxColorScheme>
+}
+
+impl<'w, 'a> MessageBoxBuilder<'w, 'a> {
+
+    /// Start building a message box without any buttons.
+    pub fn new(kind: MessageBoxKind, title: &str, message: &str) -> Self {
+        MessageBoxBuilder {
+            kind,
+            title: String::from(title),
+            message: String::from(message),
+            window: Option::None,
+            buttons: Vec::new(),
+            color_scheme: Option::None
+        }
+    }
+
+    /// Set the parent window of the message box.
+    pub fn window(mut self, window: &'w Window<'a>) -> Self {
+        self.window = Option::Some(window);
+        self
+    }
+
+    /// Add a button, which is reported by [`MessageBoxBuilder::show`] as `id` when clicked.
+    /// 
+    /// SDL reports closing the message box without a button as `-1`, so `id` should not be `-1`.
+    pub fn button(mut self, flags: MessageBoxButtonFlags, id: i32, text: &str) -> Self {
+        self.buttons.push((flags, id, String::from(text)));
+        self
+    }
+
+    /// Set the colors of the message box.
+    pub fn color_scheme(mut self, color_scheme: MessageBoxColorScheme) -> Self {
+        self.color_scheme = Option::Some(color_scheme);
+        self
+    }
+
+    /// Show the message box, blocking until it is closed.
+    /// 
+    /// Returns the id of the clicked button, or [`Option::None`] if the message
+    /// box was closed without clicking a button.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::WrongThread`] if SDL2 is initialized on another thread.
+    /// - [`SdlError::NulError`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_ShowMessageBox")]
+    pub fn show(&self) -> Result<Option<i32>, SdlError> {
+        check_init_thread("SDL_ShowMessageBox")?;
+
+        let title = CString::new(self.title.as_str())?;
+        let message = CString::new(self.message.as_str())?;
+
+        let texts = self.buttons.iter()
+            .map(|(_, _, text)| CString::new(text.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let buttons = self.buttons.iter()
+            .zip(&texts)
+            .map(|((flags, id, _), text)| SDL_MessageBoxButtonData { flags: flags.bits(), buttonid: *id, text: text.as_ptr() })
+            .collect::<Vec<_>>();
+
+        let color_scheme = self.color_scheme.map(SDL_MessageBoxColorScheme::from);
+        let data = SDL_MessageBoxData {
+            flags: self.kind.to_ll(),
+            window: self.window.map_or(ptr::null_mut(), |window| window.raw),
+            title: title.as_ptr(),
+            message: message.as_ptr(),
+            numbuttons: buttons.len() as _,
+            buttons: buttons.as_ptr(),
+            colorScheme: color_scheme.as_ref().map_or(ptr::null(), ptr::from_ref)
+        };
+
+        let mut id = -1;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_ShowMessageBox'");
+        if unsafe { SDL_ShowMessageBox(&data, &mut id) } != 0 {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
+        match id {
+            -1 => Ok(Option::None),
+            id => Ok(Option::Some(id))
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod rect;
+pub mod pixels;
+pub mod window;
+pub mod render;
+pub mod text_input;
+pub mod mouse;
+pub mod surface;
+pub mod cursor;
+pub mod clipboard;
+pub mod message_box;
+pub mod touch;
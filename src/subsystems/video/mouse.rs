@@ -0,0 +1,167 @@
+use std::marker::PhantomData;
+use bitmask_enum::bitmask;
+use crate::{sys::*, SdlError, utils::get_sys_error, subsystems::{SdlSubsystem, markers::Video}};
+use super::window::Window;
+
+/// The mouse buttons held down, as reported by [`MouseUtil`] and mouse motion events.
+#[bitmask(u32)]
+pub enum MouseButtons {
+    Left = 1 << (SDL_BUTTON_LEFT - 1),
+    Middle = 1 << (SDL_BUTTON_MIDDLE - 1),
+    Right = 1 << (SDL_BUTTON_RIGHT - 1),
+    X1 = 1 << (SDL_BUTTON_X1 - 1),
+    X2 = 1 << (SDL_BUTTON_X2 - 1)
+}
+
+/// The state of the mouse, see [`MouseUtil::mouse_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseState {
+    pub buttons: MouseButtons,
+    pub x: i32,
+    pub y: i32
+}
+
+/// Access to the state of the mouse and cursor.
+pub struct MouseUtil<'a> {
+    pub(crate) video: PhantomData<&'a SdlSubsystem<Video>>
+}
+
+impl SdlSubsystem<Video> {
+
+    /// Get access to the state of the mouse and cursor.
+    pub fn mouse(&self) -> MouseUtil<'_> {
+        MouseUtil { video: PhantomData }
+    }
+}
+
+impl MouseUtil<'_> {
+
+    /// Get the state of the mouse, with a position relative to the focused window.
+    /// 
+    /// The state is updated as events are processed, so this reflects the
+    /// mouse as of the last time the event loop was pumped.
+    #[doc(alias = "SDL_GetMouseState")]
+    pub fn mouse_state(&self) -> MouseState {
+        let (mut x, mut y) = (0, 0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetMouseState'");
+        let buttons = unsafe { SDL_GetMouseState(&mut x, &mut y) };
+
+        MouseState { buttons: MouseButtons::from(buttons), x, y }
+    }
+
+    /// Get the state of the mouse, with a position relative to the desktop.
+    /// 
+    /// Unlike [`MouseUtil::mouse_state`], this queries the platform directly
+    /// and may be more expensive.
+    #[doc(alias = "SDL_GetGlobalMouseState")]
+    pub fn global_mouse_state(&self) -> MouseState {
+        let (mut x, mut y) = (0, 0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetGlobalMouseState'");
+        let buttons = unsafe { SDL_GetGlobalMouseState(&mut x, &mut y) };
+
+        MouseState { buttons: MouseButtons::from(buttons), x, y }
+    }
+
+    /// Get the state of the mouse, with a position relative to the last call of this function.
+    #[doc(alias = "SDL_GetRelativeMouseState")]
+    pub fn relative_mouse_state(&self) -> MouseState {
+        let (mut x, mut y) = (0, 0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetRelativeMouseState'");
+        let buttons = unsafe { SDL_GetRelativeMouseState(&mut x, &mut y) };
+
+        MouseState { buttons: MouseButtons::from(buttons), x, y }
+    }
+
+    /// Set whether relative mouse mode is enabled.
+    /// 
+    /// While enabled, the cursor is hidden and constrained to the focused window,
+    /// and mouse motion events keep reporting relative motion at its edges.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SetRelativeMouseMode")]
+    pub fn set_relative_mouse_mode(&self, enabled: bool) -> Result<(), SdlError> {
+        let enabled = if enabled { SDL_bool::SDL_TRUE } else { SDL_bool::SDL_FALSE };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetRelativeMouseMode'");
+        if unsafe { SDL_SetRelativeMouseMode(enabled) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Returns true if relative mouse mode is enabled.
+    #[doc(alias = "SDL_GetRelativeMouseMode")]
+    pub fn relative_mouse_mode(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetRelativeMouseMode'");
+        unsafe { SDL_GetRelativeMouseMode() == SDL_bool::SDL_TRUE }
+    }
+
+    /// Move the mouse to the given position within a window.
+    #[doc(alias = "SDL_WarpMouseInWindow")]
+    pub fn warp_mouse_in_window(&self, window: &Window, x: i32, y: i32) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_WarpMouseInWindow'");
+        unsafe { SDL_WarpMouseInWindow(window.raw, x, y) }
+    }
+
+    /// Move the mouse to the given position on the desktop.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_WarpMouseGlobal")]
+    pub fn warp_mouse_global(&self, x: i32, y: i32) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_WarpMouseGlobal'");
+        if unsafe { SDL_WarpMouseGlobal(x, y) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Set whether mouse events are reported while the mouse is outside the focused window.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_CaptureMouse")]
+    pub fn capture_mouse(&self, enabled: bool) -> Result<(), SdlError> {
+        let enabled = if enabled { SDL_bool::SDL_TRUE } else { SDL_bool::SDL_FALSE };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_CaptureMouse'");
+        if unsafe { SDL_CaptureMouse(enabled) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Set whether the cursor is shown.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_ShowCursor")]
+    pub fn show_cursor(&self, show: bool) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_ShowCursor'");
+        if unsafe { SDL_ShowCursor(if show { SDL_ENABLE } else { SDL_DISABLE } as _) } >= 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Returns true if the cursor is shown.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_ShowCursor")]
+    pub fn is_cursor_shown(&self) -> Result<bool, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_ShowCursor'");
+        match unsafe { SDL_ShowCursor(SDL_QUERY) } {
+            result if result >= 0 => Ok(result as u32 == SDL_ENABLE),
+            _ => Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
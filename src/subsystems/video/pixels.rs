@@ -0,0 +1,45 @@
+use crate::sys::*;
+
+/// Packed pixel formats understood by SDL.
+/// 
+/// Indexed and FourCC (YUV) formats are not currently exposed.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormatEnum {
+    RGB332 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_RGB332 as _,
+    XRGB4444 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_XRGB4444 as _,
+    XBGR4444 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_XBGR4444 as _,
+    XRGB1555 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_XRGB1555 as _,
+    XBGR1555 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_XBGR1555 as _,
+    ARGB4444 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_ARGB4444 as _,
+    RGBA4444 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_RGBA4444 as _,
+    ABGR4444 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_ABGR4444 as _,
+    BGRA4444 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_BGRA4444 as _,
+    ARGB1555 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_ARGB1555 as _,
+    RGBA5551 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_RGBA5551 as _,
+    ABGR1555 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_ABGR1555 as _,
+    BGRA5551 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_BGRA5551 as _,
+    RGB565 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_RGB565 as _,
+    BGR565 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_BGR565 as _,
+    RGB24 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_RGB24 as _,
+    BGR24 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_BGR24 as _,
+    XRGB8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_XRGB8888 as _,
+    RGBX8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_RGBX8888 as _,
+    XBGR8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_XBGR8888 as _,
+    BGRX8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_BGRX8888 as _,
+    ARGB8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_ARGB8888 as _,
+    RGBA8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_RGBA8888 as _,
+    ABGR8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_ABGR8888 as _,
+    BGRA8888 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_BGRA8888 as _,
+    ARGB2101010 = SDL_PixelFormatEnum::SDL_PIXELFORMAT_ARGB2101010 as _
+}
+
+impl PixelFormatEnum {
+    /// Get the number of bytes used to store a single pixel in this format.
+    /// 
+    /// This mirrors the `SDL_BYTESPERPIXEL` macro.
+    #[doc(alias = "SDL_BYTESPERPIXEL")]
+    pub const fn bytes_per_pixel(&self) -> usize {
+        (*self as u32 & 0xFF) as _
+    }
+}
@@ -0,0 +1,29 @@
+use crate::sys::*;
+
+/// A rectangle, with the origin at the upper left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub w: u32,
+    pub h: u32
+}
+
+impl Rect {
+    /// Creates a new [`Rect`].
+    pub const fn new(x: i32, y: i32, w: u32, h: u32) -> Self {
+        Self { x, y, w, h }
+    }
+}
+
+impl From<SDL_Rect> for Rect {
+    fn from(rect: SDL_Rect) -> Self {
+        Self { x: rect.x, y: rect.y, w: rect.w.max(0) as _, h: rect.h.max(0) as _ }
+    }
+}
+
+impl From<Rect> for SDL_Rect {
+    fn from(rect: Rect) -> Self {
+        SDL_Rect { x: rect.x, y: rect.y, w: rect.w as _, h: rect.h as _ }
+    }
+}
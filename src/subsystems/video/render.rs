@@ -0,0 +1,336 @@
+use std::ptr;
+use bitmask_enum::bitmask;
+use crate::{sys::*, SdlError, utils::get_sys_error};
+use super::{window::Window, rect::Rect, pixels::PixelFormatEnum};
+
+/// Flags used when creating a [`Renderer`].
+#[bitmask(u32)]
+pub enum RendererFlags {
+    Software = SDL_RendererFlags::SDL_RENDERER_SOFTWARE as _,
+    Accelerated = SDL_RendererFlags::SDL_RENDERER_ACCELERATED as _,
+    PresentVsync = SDL_RendererFlags::SDL_RENDERER_PRESENTVSYNC as _,
+    TargetTexture = SDL_RendererFlags::SDL_RENDERER_TARGETTEXTURE as _
+}
+
+/// A 2D rendering context for a [`Window`], destroyed on drop.
+pub struct Renderer<'a> {
+    pub(crate) raw: *mut SDL_Renderer,
+    pub(crate) window: Window<'a>
+}
+
+impl Drop for Renderer<'_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_DestroyRenderer' via Renderer drop.");
+        unsafe { SDL_DestroyRenderer(self.raw) }
+    }
+}
+
+impl<'a> Renderer<'a> {
+
+    /// Get the [`Window`] this renderer draws to.
+    pub fn window(&self) -> &Window<'a> {
+        &self.window
+    }
+
+    /// Get the output size of this renderer, in pixels.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GetRendererOutputSize")]
+    pub fn output_size(&self) -> Result<(u32, u32), SdlError> {
+        let (mut w, mut h) = (0, 0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetRendererOutputSize'");
+        if unsafe { SDL_GetRendererOutputSize(self.raw, &mut w, &mut h) } == 0 {
+            Ok((w as _, h as _))
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Set the color used for drawing operations, including [`Renderer::clear`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SetRenderDrawColor")]
+    pub fn set_draw_color(&mut self, r: u8, g: u8, b: u8, a: u8) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetRenderDrawColor'");
+        if unsafe { SDL_SetRenderDrawColor(self.raw, r, g, b, a) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Clear the current rendering target with the drawing color.
+    /// 
+    /// This ignores the viewport and clip rectangle.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RenderClear")]
+    pub fn clear(&mut self) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderClear'");
+        if unsafe { SDL_RenderClear(self.raw) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Update the screen with any rendering performed since the previous call.
+    #[doc(alias = "SDL_RenderPresent")]
+    pub fn present(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderPresent'");
+        unsafe { SDL_RenderPresent(self.raw) }
+    }
+
+    /// Set a device independent resolution for rendering.
+    /// 
+    /// Rendering is scaled to fit the output while preserving the aspect ratio,
+    /// letterboxing as needed. Passing a size of `(0, 0)` disables logical scaling.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RenderSetLogicalSize")]
+    pub fn set_logical_size(&mut self, width: u32, height: u32) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderSetLogicalSize'");
+        if unsafe { SDL_RenderSetLogicalSize(self.raw, width as _, height as _) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the device independent resolution for rendering.
+    /// 
+    /// Returns [`Option::None`] if no logical size has been set.
+    #[doc(alias = "SDL_RenderGetLogicalSize")]
+    pub fn logical_size(&self) -> Option<(u32, u32)> {
+        let (mut w, mut h) = (0, 0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderGetLogicalSize'");
+        unsafe { SDL_RenderGetLogicalSize(self.raw, &mut w, &mut h) };
+
+        match (w, h) {
+            (0, 0) => Option::None,
+            _ => Option::Some((w as _, h as _))
+        }
+    }
+
+    /// Set whether to force integer scales for resolution-independent rendering.
+    /// 
+    /// This only has an effect when a logical size is set, see [`Renderer::set_logical_size`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RenderSetIntegerScale")]
+    pub fn set_integer_scale(&mut self, enable: bool) -> Result<(), SdlError> {
+        let enable = if enable { SDL_bool::SDL_TRUE } else { SDL_bool::SDL_FALSE };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderSetIntegerScale'");
+        if unsafe { SDL_RenderSetIntegerScale(self.raw, enable) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get whether integer scales are forced for resolution-independent rendering.
+    #[doc(alias = "SDL_RenderGetIntegerScale")]
+    pub fn integer_scale(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderGetIntegerScale'");
+        unsafe { SDL_RenderGetIntegerScale(self.raw) == SDL_bool::SDL_TRUE }
+    }
+
+    /// Set the drawing area for rendering on the current target.
+    /// 
+    /// Passing [`Option::None`] sets the viewport to the entire target.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RenderSetViewport")]
+    pub fn set_viewport(&mut self, rect: Option<Rect>) -> Result<(), SdlError> {
+        let rect = rect.map(SDL_Rect::from);
+        let rect = rect.as_ref().map_or(ptr::null(), |r| r as *const _);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderSetViewport'");
+        if unsafe { SDL_RenderSetViewport(self.raw, rect) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the drawing area for the current target.
+    #[doc(alias = "SDL_RenderGetViewport")]
+    pub fn viewport(&self) -> Rect {
+        let mut rect = SDL_Rect { x: 0, y: 0, w: 0, h: 0 };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderGetViewport'");
+        unsafe { SDL_RenderGetViewport(self.raw, &mut rect) };
+
+        rect.into()
+    }
+
+    /// Set the clip rectangle for rendering on the current target.
+    /// 
+    /// Passing [`Option::None`] disables clipping.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RenderSetClipRect")]
+    pub fn set_clip_rect(&mut self, rect: Option<Rect>) -> Result<(), SdlError> {
+        let rect = rect.map(SDL_Rect::from);
+        let rect = rect.as_ref().map_or(ptr::null(), |r| r as *const _);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderSetClipRect'");
+        if unsafe { SDL_RenderSetClipRect(self.raw, rect) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the clip rectangle for the current target.
+    /// 
+    /// Returns [`Option::None`] if clipping is disabled.
+    #[doc(alias = "SDL_RenderGetClipRect")]
+    #[doc(alias = "SDL_RenderIsClipEnabled")]
+    pub fn clip_rect(&self) -> Option<Rect> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderIsClipEnabled'");
+        if unsafe { SDL_RenderIsClipEnabled(self.raw) } == SDL_bool::SDL_FALSE {
+            return Option::None;
+        }
+
+        let mut rect = SDL_Rect { x: 0, y: 0, w: 0, h: 0 };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderGetClipRect'");
+        unsafe { SDL_RenderGetClipRect(self.raw, &mut rect) };
+
+        Option::Some(rect.into())
+    }
+
+    /// Set the drawing scale for rendering on the current target.
+    /// 
+    /// Note: Setting a logical size overrides the scale, see [`Renderer::set_logical_size`].
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RenderSetScale")]
+    pub fn set_scale(&mut self, scale_x: f32, scale_y: f32) -> Result<(), SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderSetScale'");
+        if unsafe { SDL_RenderSetScale(self.raw, scale_x, scale_y) } == 0 {
+            Ok(())
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the drawing scale for the current target.
+    #[doc(alias = "SDL_RenderGetScale")]
+    pub fn scale(&self) -> (f32, f32) {
+        let (mut x, mut y) = (0.0, 0.0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderGetScale'");
+        unsafe { SDL_RenderGetScale(self.raw, &mut x, &mut y) };
+
+        (x, y)
+    }
+
+    /// Get the ratio between the renderer's output size and the window size.
+    fn pixel_density(&self) -> (f32, f32) {
+        let (window_w, window_h) = self.window.size();
+        let (output_w, output_h) = self.output_size().unwrap_or((window_w, window_h));
+
+        if window_w == 0 || window_h == 0 {
+            return (1.0, 1.0);
+        }
+
+        (output_w as f32 / window_w as f32, output_h as f32 / window_h as f32)
+    }
+
+    /// Get the viewport in output pixels, clamped to the output size.
+    fn physical_viewport(&self) -> Result<Rect, SdlError> {
+        let viewport = self.viewport();
+        let (scale_x, scale_y) = self.scale();
+        let (output_w, output_h) = self.output_size()?;
+
+        let x = ((viewport.x as f32 * scale_x).round() as i32).clamp(0, output_w as i32);
+        let y = ((viewport.y as f32 * scale_y).round() as i32).clamp(0, output_h as i32);
+        let w = ((viewport.w as f32 * scale_x).round() as u32).min(output_w - x as u32);
+        let h = ((viewport.h as f32 * scale_y).round() as u32).min(output_h - y as u32);
+
+        Ok(Rect::new(x, y, w, h))
+    }
+
+    /// Get logical coordinates of a point in the window, taking the
+    /// logical size, viewport and scale into account.
+    /// 
+    /// This mirrors `SDL_RenderWindowToLogical`, which is not available in the
+    /// bindings for the linked SDL version.
+    #[doc(alias = "SDL_RenderWindowToLogical")]
+    pub fn window_to_logical(&self, window_x: i32, window_y: i32) -> (f32, f32) {
+        let (density_x, density_y) = self.pixel_density();
+        let (scale_x, scale_y) = self.scale();
+        let viewport = self.viewport();
+
+        (
+            window_x as f32 * density_x / scale_x - viewport.x as f32,
+            window_y as f32 * density_y / scale_y - viewport.y as f32
+        )
+    }
+
+    /// Get window coordinates of a point in logical coordinates, taking the
+    /// logical size, viewport and scale into account.
+    /// 
+    /// This mirrors `SDL_RenderLogicalToWindow`, which is not available in the
+    /// bindings for the linked SDL version.
+    #[doc(alias = "SDL_RenderLogicalToWindow")]
+    pub fn logical_to_window(&self, logical_x: f32, logical_y: f32) -> (i32, i32) {
+        let (density_x, density_y) = self.pixel_density();
+        let (scale_x, scale_y) = self.scale();
+        let viewport = self.viewport();
+
+        (
+            ((logical_x + viewport.x as f32) * scale_x / density_x) as _,
+            ((logical_y + viewport.y as f32) * scale_y / density_y) as _
+        )
+    }
+
+    /// Read pixels from the current rendering target into an owned buffer.
+    /// 
+    /// `rect` is the area to read in output pixels, or [`Option::None`] for the
+    /// entire viewport. Rows in the returned buffer are tightly packed, with a pitch of
+    /// `width * format.bytes_per_pixel()`.
+    /// 
+    /// Note: This is a very slow operation, and should not be used frequently.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::InvalidValue`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_RenderReadPixels")]
+    pub fn read_pixels(&self, rect: Option<Rect>, format: PixelFormatEnum) -> Result<Vec<u8>, SdlError> {
+        let area = match rect {
+            Some(rect) => rect,
+            Option::None => self.physical_viewport()?
+        };
+
+        if area.w > i32::MAX as u32 || area.h > i32::MAX as u32 {
+            return Err(SdlError::InvalidValue(format!("Rect size {}x{} is too large.", area.w, area.h)));
+        }
+
+        let pitch = area.w as usize * format.bytes_per_pixel();
+        let mut pixels = vec![0u8; pitch * area.h as usize];
+
+        // SDL clips the rect to the viewport, so it never writes more than `area`.
+        let rect = SDL_Rect::from(area);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RenderReadPixels'");
+        if unsafe { SDL_RenderReadPixels(self.raw, &rect, format as _, pixels.as_mut_ptr() as _, pitch as _) } == 0 {
+            Ok(pixels)
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
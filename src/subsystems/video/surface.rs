@@ -0,0 +1,100 @@
+use std::slice;
+use crate::{sys::*, SdlError, utils::get_sys_error};
+use super::pixels::PixelFormatEnum;
+
+/// A buffer of pixels in system memory, freed on drop.
+/// 
+/// Surfaces do not require the Video subsystem to be initialized, and SDL allows
+/// them to be created on any thread.
+pub struct Surface {
+    pub(crate) raw: *mut SDL_Surface
+}
+
+impl Drop for Surface {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_FreeSurface' via Surface drop.");
+        unsafe { SDL_FreeSurface(self.raw) }
+    }
+}
+
+/// Unlocks a surface locked via `SDL_LockSurface` on drop.
+struct SurfaceLock {
+    raw: *mut SDL_Surface
+}
+
+impl Drop for SurfaceLock {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_UnlockSurface' via SurfaceLock drop.");
+        unsafe { SDL_UnlockSurface(self.raw) }
+    }
+}
+
+impl Surface {
+
+    /// Create a surface with the specified size and pixel format, initialized to zero.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_CreateRGBSurfaceWithFormat")]
+    pub fn new(width: u32, height: u32, format: PixelFormatEnum) -> Result<Surface, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_CreateRGBSurfaceWithFormat'");
+        let raw = unsafe {
+            SDL_CreateRGBSurfaceWithFormat(0, width as _, height as _, (format.bytes_per_pixel() * 8) as _, format as _)
+        };
+
+        if !raw.is_null() {
+            Ok(Surface { raw })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the width of this surface in pixels.
+    pub fn width(&self) -> u32 {
+        unsafe { (*self.raw).w as _ }
+    }
+
+    /// Get the height of this surface in pixels.
+    pub fn height(&self) -> u32 {
+        unsafe { (*self.raw).h as _ }
+    }
+
+    /// Get the length of a row of pixels in bytes.
+    pub fn pitch(&self) -> u32 {
+        unsafe { (*self.raw).pitch as _ }
+    }
+
+    /// Lock this surface and give `f` access to its pixels, row by row with a
+    /// stride of [`Surface::pitch`] bytes.
+    /// 
+    /// Surfaces with a width or height of zero have no pixels:
+    /// 
+    /// ```
+    /// # use sdl2_rs::subsystems::video::{surface::Surface, pixels::PixelFormatEnum};
+    /// let mut surface = Surface::new(0, 16, PixelFormatEnum::RGBA8888)?;
+    /// assert_eq!(surface.with_lock_mut(|pixels| pixels.len())?, 0);
+    /// # Ok::<(), sdl2_rs::SdlError>(())
+    /// ```
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_LockSurface")]
+    pub fn with_lock_mut<R>(&mut self, f: impl FnOnce(&mut [u8]) -> R) -> Result<R, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_LockSurface'");
+        if unsafe { SDL_LockSurface(self.raw) } != 0 {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
+        // Unlocks the surface even if `f` panics.
+        let _lock = SurfaceLock { raw: self.raw };
+
+        // SDL does not allocate pixels for surfaces with a width or height of zero.
+        let pixels = unsafe { (*self.raw).pixels };
+        if pixels.is_null() {
+            return Ok(f(&mut []));
+        }
+
+        let len = self.pitch() as usize * self.height() as usize;
+        Ok(f(unsafe { slice::from_raw_parts_mut(pixels.cast(), len) }))
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{sys::*, subsystems::{SdlSubsystem, markers::Video}};
+use super::{window::Window, rect::Rect};
+
+impl SdlSubsystem<Video> {
+
+    /// Start accepting Unicode text input events, see [`Event::TextInput`] and [`Event::TextEditing`].
+    /// 
+    /// On some platforms this shows the screen keyboard, or an input method (IME) window.
+    /// 
+    /// [`Event::TextInput`]: crate::subsystems::events::event::Event::TextInput
+    /// [`Event::TextEditing`]: crate::subsystems::events::event::Event::TextEditing
+    #[doc(alias = "SDL_StartTextInput")]
+    pub fn start_text_input(&self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_StartTextInput'");
+        unsafe { SDL_StartTextInput() }
+    }
+
+    /// Stop accepting Unicode text input events.
+    #[doc(alias = "SDL_StopTextInput")]
+    pub fn stop_text_input(&self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_StopTextInput'");
+        unsafe { SDL_StopTextInput() }
+    }
+
+    /// Returns true if Unicode text input events are enabled.
+    #[doc(alias = "SDL_IsTextInputActive")]
+    pub fn is_text_input_active(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_IsTextInputActive'");
+        unsafe { SDL_IsTextInputActive() == SDL_bool::SDL_TRUE }
+    }
+
+    /// Set the area used to type Unicode text, so that an input method (IME)
+    /// can place its candidate list without covering it.
+    #[doc(alias = "SDL_SetTextInputRect")]
+    pub fn set_text_input_rect(&self, rect: Rect) {
+        let mut rect = SDL_Rect::from(rect);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_SetTextInputRect'");
+        unsafe { SDL_SetTextInputRect(&mut rect) }
+    }
+
+    /// Returns true if the platform has a screen keyboard.
+    #[doc(alias = "SDL_HasScreenKeyboardSupport")]
+    pub fn has_screen_keyboard_support(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_HasScreenKeyboardSupport'");
+        unsafe { SDL_HasScreenKeyboardSupport() == SDL_bool::SDL_TRUE }
+    }
+}
+
+impl Window<'_> {
+
+    /// Returns true if the screen keyboard is shown for this window.
+    #[doc(alias = "SDL_IsScreenKeyboardShown")]
+    pub fn is_screen_keyboard_shown(&self) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_IsScreenKeyboardShown'");
+        unsafe { SDL_IsScreenKeyboardShown(self.raw) == SDL_bool::SDL_TRUE }
+    }
+}
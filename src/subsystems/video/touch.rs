@@ -0,0 +1,243 @@
+use std::{ffi::c_void, io::{Read, Write}, mem, slice};
+use crate::{sys::*, SdlError, utils::get_sys_error, subsystems::{SdlSubsystem, markers::Video}};
+
+/// The touch ID of touch events synthesized from mouse events.
+pub const MOUSE_TOUCH_ID: i64 = -1;
+
+/// The mouse ID of mouse events synthesized from touch events.
+pub const TOUCH_MOUSE_ID: u32 = u32::MAX;
+
+/// How the positions reported by a touch device relate to the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchDeviceType {
+    /// A touch screen, where positions are relative to the window.
+    Direct,
+    /// A trackpad with absolute positions, relative to the trackpad.
+    IndirectAbsolute,
+    /// A trackpad with relative positions, moving the cursor.
+    IndirectRelative
+}
+
+/// The state of a finger on a touch device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Finger {
+    pub id: i64,
+    /// Normalized between 0 and 1.
+    pub x: f32,
+    /// Normalized between 0 and 1.
+    pub y: f32,
+    /// Normalized between 0 and 1.
+    pub pressure: f32
+}
+
+/// `SDL_TouchDeviceType` is a Rust enum in the bindings, so it is read through
+/// this signature to avoid reading an unknown variant.
+type GetTouchDeviceType = unsafe extern "C" fn(SDL_TouchID) -> i32;
+
+impl SdlSubsystem<Video> {
+
+    /// Get the number of touch devices.
+    #[doc(alias = "SDL_GetNumTouchDevices")]
+    pub fn num_touch_devices(&self) -> u32 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetNumTouchDevices'");
+        unsafe { SDL_GetNumTouchDevices() }.max(0) as _
+    }
+
+    /// Get the touch ID of the touch device at `index`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_GetTouchDevice")]
+    pub fn touch_device(&self, index: u32) -> Result<i64, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetTouchDevice'");
+        match unsafe { SDL_GetTouchDevice(index as _) } {
+            0 => Err(SdlError::SysError(get_sys_error().unwrap())),
+            touch_id => Ok(touch_id)
+        }
+    }
+
+    /// Get the kind of the touch device with `touch_id`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if there is no such touch device.
+    #[doc(alias = "SDL_GetTouchDeviceType")]
+    pub fn touch_device_type(&self, touch_id: i64) -> Result<TouchDeviceType, SdlError> {
+        let touch_device_type: GetTouchDeviceType = unsafe {
+            mem::transmute(SDL_GetTouchDeviceType as unsafe extern "C" fn(SDL_TouchID) -> SDL_TouchDeviceType)
+        };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetTouchDeviceType'");
+        match unsafe { touch_device_type(touch_id) } {
+            x if x == SDL_TouchDeviceType::SDL_TOUCH_DEVICE_DIRECT as i32 => Ok(TouchDeviceType::Direct),
+            x if x == SDL_TouchDeviceType::SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE as i32 => Ok(TouchDeviceType::IndirectAbsolute),
+            x if x == SDL_TouchDeviceType::SDL_TOUCH_DEVICE_INDIRECT_RELATIVE as i32 => Ok(TouchDeviceType::IndirectRelative),
+            _ => Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the number of fingers on the touch device with `touch_id`.
+    /// 
+    /// Returns zero if there is no such touch device.
+    #[doc(alias = "SDL_GetNumTouchFingers")]
+    pub fn num_touch_fingers(&self, touch_id: i64) -> u32 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetNumTouchFingers'");
+        unsafe { SDL_GetNumTouchFingers(touch_id) }.max(0) as _
+    }
+
+    /// Get the state of a finger on the touch device with `touch_id`, where
+    /// `index` is below [`SdlSubsystem<Video>::num_touch_fingers`](SdlSubsystem::num_touch_fingers).
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`] if there is no such touch device or finger.
+    #[doc(alias = "SDL_GetTouchFinger")]
+    pub fn touch_finger(&self, touch_id: i64, index: u32) -> Result<Finger, SdlError> {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetTouchFinger'");
+        let finger = unsafe { SDL_GetTouchFinger(touch_id, index as _) };
+
+        if !finger.is_null() {
+            let finger = unsafe { *finger };
+            Ok(Finger { id: finger.id, x: finger.x, y: finger.y, pressure: finger.pressure })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+
+    /// Get the state of every finger on the touch device with `touch_id`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    pub fn touch_fingers(&self, touch_id: i64) -> Result<Vec<Finger>, SdlError> {
+        (0..self.num_touch_fingers(touch_id))
+            .map(|index| self.touch_finger(touch_id, index))
+            .collect()
+    }
+
+    /// Record the next gesture on the touch device with `touch_id`, or on every
+    /// touch device if [`None`], as a dollar template.
+    /// 
+    /// An [`Event::DollarRecord`](crate::subsystems::events::event::Event::DollarRecord)
+    /// carrying the ID of the template is sent once the gesture is recorded.
+    /// Returns false if a `touch_id` is given and there is no such touch device.
+    #[doc(alias = "SDL_RecordGesture")]
+    pub fn record_gesture(&self, touch_id: Option<i64>) -> bool {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RecordGesture'");
+        unsafe { SDL_RecordGesture(touch_id.unwrap_or(-1)) == 1 }
+    }
+
+    /// Write every dollar template to `writer`, returning the number of templates written.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::IoError`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_SaveAllDollarTemplates")]
+    pub fn save_dollar_templates<W: Write>(&self, mut writer: W) -> Result<u32, SdlError> {
+        let (count, data) = write_to_vec(|rw| {
+            #[cfg(feature = "log")] debug!("Calling 'SDL_SaveAllDollarTemplates'");
+            unsafe { SDL_SaveAllDollarTemplates(rw) }
+        })?;
+
+        writer.write_all(&data).map_err(|error| SdlError::IoError(error.to_string()))?;
+        Ok(count as _)
+    }
+
+    /// Write the dollar template with `gesture_id` to `writer`.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::IoError`]
+    /// - [`SdlError::SysError`] if there is no such template.
+    #[doc(alias = "SDL_SaveDollarTemplate")]
+    pub fn save_dollar_template<W: Write>(&self, gesture_id: i64, mut writer: W) -> Result<(), SdlError> {
+        let (saved, data) = write_to_vec(|rw| {
+            #[cfg(feature = "log")] debug!("Calling 'SDL_SaveDollarTemplate'");
+            unsafe { SDL_SaveDollarTemplate(gesture_id, rw) }
+        })?;
+
+        if saved != 1 {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
+        writer.write_all(&data).map_err(|error| SdlError::IoError(error.to_string()))
+    }
+
+    /// Read dollar templates from `reader` into the touch device with `touch_id`,
+    /// or into every touch device if [`None`], returning the number of templates read.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::IoError`]
+    /// - [`SdlError::InvalidValue`] if the templates are larger than 2 GiB.
+    /// - [`SdlError::SysError`] if there is no such touch device or no template could be read.
+    #[doc(alias = "SDL_LoadDollarTemplates")]
+    pub fn load_dollar_templates<R: Read>(&self, touch_id: Option<i64>, mut reader: R) -> Result<u32, SdlError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).map_err(|error| SdlError::IoError(error.to_string()))?;
+
+        // SDL refuses to open a stream over zero bytes.
+        if data.is_empty() {
+            return Ok(0);
+        }
+
+        let len = i32::try_from(data.len())
+            .map_err(|_| SdlError::InvalidValue(String::from("The dollar templates are too large.")))?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RWFromConstMem'");
+        let rw = unsafe { SDL_RWFromConstMem(data.as_ptr().cast(), len) };
+        if rw.is_null() {
+            return Err(SdlError::SysError(get_sys_error().unwrap()));
+        }
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_LoadDollarTemplates'");
+        let loaded = unsafe { SDL_LoadDollarTemplates(touch_id.unwrap_or(-1), rw) };
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_RWclose'");
+        unsafe { (*rw).close.unwrap()(rw) };
+
+        if loaded >= 0 {
+            Ok(loaded as _)
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
+
+/// Call `f` with a write-only stream collecting everything written to it,
+/// returning the result of `f` along with the written bytes.
+fn write_to_vec(f: impl FnOnce(*mut SDL_RWops) -> i32) -> Result<(i32, Vec<u8>), SdlError> {
+    unsafe extern "C" fn write(context: *mut SDL_RWops, ptr: *const c_void, size: size_t, num: size_t) -> size_t {
+        let data = &mut *(*context).hidden.unknown.data1.cast::<Vec<u8>>();
+
+        // Panics must not unwind into SDL, so failures are reported as a short write instead.
+        let Option::Some(len) = size.checked_mul(num).and_then(|len| usize::try_from(len).ok()) else {
+            return 0;
+        };
+        if data.try_reserve(len).is_err() {
+            return 0;
+        }
+
+        data.extend_from_slice(slice::from_raw_parts(ptr.cast::<u8>(), len));
+        num
+    }
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_AllocRW'");
+    let rw = unsafe { SDL_AllocRW() };
+    if rw.is_null() {
+        return Err(SdlError::SysError(get_sys_error().unwrap()));
+    }
+
+    let mut data = Vec::new();
+    let result = unsafe {
+        (*rw).size = Option::None;
+        (*rw).seek = Option::None;
+        (*rw).read = Option::None;
+        (*rw).write = Option::Some(write);
+        (*rw).close = Option::None;
+        (*rw).type_ = SDL_RWOPS_UNKNOWN;
+        (*rw).hidden.unknown.data1 = (&mut data as *mut Vec<u8>).cast();
+
+        f(rw)
+    };
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_FreeRW'");
+    unsafe { SDL_FreeRW(rw) };
+
+    Ok((result, data))
+}
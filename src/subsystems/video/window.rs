@@ -0,0 +1,117 @@
+use std::{ffi::CString, marker::PhantomData};
+use bitmask_enum::bitmask;
+use crate::{sys::*, SdlError, utils::get_sys_error, subsystems::{SdlSubsystem, markers::Video}};
+use super::render::{Renderer, RendererFlags};
+
+/// Flags used when creating a [`Window`].
+#[bitmask(u32)]
+pub enum WindowFlags {
+    Fullscreen = SDL_WindowFlags::SDL_WINDOW_FULLSCREEN as _,
+    FullscreenDesktop = SDL_WindowFlags::SDL_WINDOW_FULLSCREEN_DESKTOP as _,
+    OpenGl = SDL_WindowFlags::SDL_WINDOW_OPENGL as _,
+    Vulkan = SDL_WindowFlags::SDL_WINDOW_VULKAN as _,
+    Metal = SDL_WindowFlags::SDL_WINDOW_METAL as _,
+    Shown = SDL_WindowFlags::SDL_WINDOW_SHOWN as _,
+    Hidden = SDL_WindowFlags::SDL_WINDOW_HIDDEN as _,
+    Borderless = SDL_WindowFlags::SDL_WINDOW_BORDERLESS as _,
+    Resizable = SDL_WindowFlags::SDL_WINDOW_RESIZABLE as _,
+    Minimized = SDL_WindowFlags::SDL_WINDOW_MINIMIZED as _,
+    Maximized = SDL_WindowFlags::SDL_WINDOW_MAXIMIZED as _,
+    AllowHighDpi = SDL_WindowFlags::SDL_WINDOW_ALLOW_HIGHDPI as _,
+    AlwaysOnTop = SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as _,
+    SkipTaskbar = SDL_WindowFlags::SDL_WINDOW_SKIP_TASKBAR as _,
+    Utility = SDL_WindowFlags::SDL_WINDOW_UTILITY as _,
+    Tooltip = SDL_WindowFlags::SDL_WINDOW_TOOLTIP as _,
+    PopupMenu = SDL_WindowFlags::SDL_WINDOW_POPUP_MENU as _
+}
+
+/// An SDL window, destroyed on drop.
+pub struct Window<'a> {
+    pub(crate) raw: *mut SDL_Window,
+    pub(crate) video: PhantomData<&'a SdlSubsystem<Video>>
+}
+
+impl Drop for Window<'_> {
+    fn drop(&mut self) {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_DestroyWindow' via Window drop.");
+        unsafe { SDL_DestroyWindow(self.raw) }
+    }
+}
+
+impl SdlSubsystem<Video> {
+
+    /// Create a window with the specified title, size and flags.
+    /// 
+    /// The window is placed at an undefined position chosen by the video driver.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::NulError`]
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_CreateWindow")]
+    pub fn create_window(&self, title: &str, width: u32, height: u32, flags: WindowFlags) -> Result<Window<'_>, SdlError> {
+        let title = CString::new(title)?;
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_CreateWindow'");
+        let raw = unsafe {
+            SDL_CreateWindow(
+                title.as_ptr(),
+                SDL_WINDOWPOS_UNDEFINED_MASK as _,
+                SDL_WINDOWPOS_UNDEFINED_MASK as _,
+                width as _,
+                height as _,
+                flags.bits()
+            )
+        };
+
+        if !raw.is_null() {
+            Ok(Window { raw, video: PhantomData })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
+
+impl<'a> Window<'a> {
+
+    /// Get the numeric ID of this window.
+    #[doc(alias = "SDL_GetWindowID")]
+    pub fn id(&self) -> u32 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetWindowID'");
+        unsafe { SDL_GetWindowID(self.raw) }
+    }
+
+    /// Get the size of this window's client area, in screen coordinates.
+    #[doc(alias = "SDL_GetWindowSize")]
+    pub fn size(&self) -> (u32, u32) {
+        let (mut w, mut h) = (0, 0);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_GetWindowSize'");
+        unsafe { SDL_GetWindowSize(self.raw, &mut w, &mut h) };
+
+        (w as _, h as _)
+    }
+
+    /// Create a 2D rendering context for this window.
+    /// 
+    /// `index` selects the rendering driver to initialize, or [`Option::None`] to
+    /// use the first one supporting `flags`.
+    /// 
+    /// The returned [`Renderer`] takes ownership of the window, as SDL destroys
+    /// a window's renderer along with it.
+    /// 
+    /// ### Errors
+    /// - [`SdlError::SysError`]
+    #[doc(alias = "SDL_CreateRenderer")]
+    pub fn create_renderer(self, index: Option<u32>, flags: RendererFlags) -> Result<Renderer<'a>, SdlError> {
+        let index = index.map(|i| i as i32).unwrap_or(-1);
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_CreateRenderer'");
+        let raw = unsafe { SDL_CreateRenderer(self.raw, index, flags.bits()) };
+
+        if !raw.is_null() {
+            Ok(Renderer { raw, window: self })
+        } else {
+            Err(SdlError::SysError(get_sys_error().unwrap()))
+        }
+    }
+}
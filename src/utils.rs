@@ -1,5 +1,6 @@
-use std::ffi::CStr;
-use crate::sys::*;
+use std::{ffi::{CStr, CString, c_void}, mem, ptr, sync::OnceLock};
+use semver::Version;
+use crate::{sys::*, SdlContext, SdlError, version::linked_version};
 
 pub fn get_sys_error() -> Option<String> {
     #[cfg(feature = "log")] debug!("Calling 'SDL_GetError'");
@@ -7,3 +8,78 @@ pub fn get_sys_error() -> Option<String> {
         CStr::from_ptr(SDL_GetError()).to_str().ok().map(|s| s.to_string())
     }
 }
+
+/// Returns [`SdlError::WrongThread`] if SDL2 is initialized, but not on the current thread.
+pub fn check_init_thread(function: &str) -> Result<(), SdlError> {
+    if SdlContext::init_thread().map_or(true, |thread| thread == std::thread::current().id()) {
+        Ok(())
+    } else {
+        Err(SdlError::WrongThread(format!("'{}' must be called on the thread SDL2 was initialized on.", function)))
+    }
+}
+
+/// Returns [`SdlError::Unsupported`] if the linked SDL2 library is older than `required`.
+pub fn require_version(function: &str, required: Version) -> Result<(), SdlError> {
+    let linked = linked_version();
+
+    if linked >= required {
+        Ok(())
+    } else {
+        Err(SdlError::Unsupported(format!("'{}' requires SDL {}, but SDL {} is linked.", function, required, linked)))
+    }
+}
+
+/// Look up `function` in the linked SDL2 library, for functions newer than the bindings.
+/// 
+/// ### Errors
+/// - [`SdlError::Unsupported`] if the linked SDL2 library is older than `required`,
+///   or does not export `function`.
+/// 
+/// ### Safety
+/// `F` must be the `unsafe extern "C" fn` type matching the C declaration of `function`.
+pub unsafe fn load_function<F: Copy>(function: &str, required: Version) -> Result<F, SdlError> {
+    // The handle of the already loaded SDL2 library, or null if it cannot be found.
+    static LIBRARY: OnceLock<usize> = OnceLock::new();
+
+    assert_eq!(mem::size_of::<F>(), mem::size_of::<*mut c_void>());
+
+    require_version(function, required)?;
+
+    let library = *LIBRARY.get_or_init(|| {
+        // On Windows the loaded DLL is found by name. Elsewhere, a null path looks
+        // in the program itself and the libraries it was linked against.
+        #[cfg(windows)] let path = b"SDL2.dll\0".as_ptr().cast();
+        #[cfg(not(windows))] let path = ptr::null();
+
+        #[cfg(feature = "log")] debug!("Calling 'SDL_LoadObject'");
+        SDL_LoadObject(path) as usize
+    });
+
+    let name = CString::new(function)?;
+    let symbol = if library != 0 {
+        #[cfg(feature = "log")] debug!("Calling 'SDL_LoadFunction'");
+        SDL_LoadFunction(library as *mut c_void, name.as_ptr())
+    } else {
+        ptr::null_mut()
+    };
+
+    if symbol.is_null() {
+        Err(SdlError::Unsupported(format!("'{}' is not exported by the linked SDL2 library.", function)))
+    } else {
+        Ok(mem::transmute_copy(&symbol))
+    }
+}
+
+/// Copies a string allocated by SDL, then frees it.
+pub fn take_sdl_string(text: *mut std::ffi::c_char) -> Result<String, SdlError> {
+    if text.is_null() {
+        return Err(SdlError::SysError(get_sys_error().unwrap()));
+    }
+
+    let result = unsafe { CStr::from_ptr(text) }.to_string_lossy().into_owned();
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_free'");
+    unsafe { SDL_free(text.cast()) };
+
+    Ok(result)
+}
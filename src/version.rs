@@ -3,3 +3,14 @@ use semver::Version;
 
 /// The SDL2 version used for this library.
 pub const SDL2_VERSION: Version = Version::new(SDL_MAJOR_VERSION as _, SDL_MINOR_VERSION as _, SDL_PATCHLEVEL as _);
+
+/// Get the version of the SDL2 library linked at runtime, which may be newer than [`SDL2_VERSION`].
+#[doc(alias = "SDL_GetVersion")]
+pub fn linked_version() -> Version {
+    let mut version = SDL_version { major: 0, minor: 0, patch: 0 };
+
+    #[cfg(feature = "log")] debug!("Calling 'SDL_GetVersion'");
+    unsafe { SDL_GetVersion(&mut version) };
+
+    Version::new(version.major as _, version.minor as _, version.patch as _)
+}